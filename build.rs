@@ -0,0 +1,49 @@
+//! Discovers every `src/dayNN.rs` and generates the module declarations
+//! and `SOLUTIONS` registry that `lib.rs` includes, so adding a day only
+//! requires creating its file.
+
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+fn main() {
+    let manifest_dir = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap());
+    let src_dir = manifest_dir.join("src");
+    println!("cargo:rerun-if-changed={}", src_dir.display());
+
+    let mut days: Vec<(u8, PathBuf)> = fs::read_dir(&src_dir)
+        .expect("Failed to read src directory")
+        .filter_map(|entry| entry.ok().map(|e| e.path()))
+        .filter_map(|path| day_number(&path).map(|day| (day, path)))
+        .collect();
+    days.sort();
+
+    let mut generated = String::new();
+    for (day, path) in &days {
+        generated.push_str(&format!(
+            "#[path = {:?}]\npub mod day{:02};\n",
+            path.display().to_string(),
+            day
+        ));
+    }
+
+    generated.push_str("\n/// Every implemented day, looked up by `DynSolution::day()`.\n");
+    generated.push_str("pub static SOLUTIONS: &[&dyn DynSolution] = &[\n");
+    for (day, _) in &days {
+        generated.push_str(&format!("    &day{:02}::Day{:02},\n", day, day));
+    }
+    generated.push_str("];\n");
+
+    let out_path = PathBuf::from(env::var("OUT_DIR").unwrap()).join("days.rs");
+    fs::write(out_path, generated).expect("Failed to write generated day list");
+}
+
+/// `src/day05.rs` -> `Some(5)`; anything else -> `None`.
+fn day_number(path: &Path) -> Option<u8> {
+    let name = path.file_name()?.to_str()?;
+    let digits = name.strip_prefix("day")?.strip_suffix(".rs")?;
+    if digits.len() != 2 || !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    digits.parse().ok().filter(|day| (1..=25).contains(day))
+}
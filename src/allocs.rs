@@ -0,0 +1,242 @@
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+use std::fmt;
+use std::hint::black_box;
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::AocResult;
+use crate::runner::Parts;
+use crate::solution::DynSolution;
+
+/// Whether allocations are being counted, i.e. whether this build has the
+/// `count-allocs` feature.
+pub const ENABLED: bool = cfg!(feature = "count-allocs");
+
+/// Global allocator that forwards to [`System`] and keeps per-thread
+/// counts, so days running in parallel don't see each other's allocations.
+pub struct Counting;
+
+#[cfg(feature = "count-allocs")]
+#[global_allocator]
+static GLOBAL: Counting = Counting;
+
+/// Running totals for one thread. Live bytes can go negative when a thread
+/// frees memory another thread allocated.
+struct Counters {
+    allocations: Cell<u64>,
+    bytes: Cell<u64>,
+    live: Cell<i64>,
+    peak: Cell<i64>,
+}
+
+impl Counters {
+    const fn new() -> Counters {
+        Counters {
+            allocations: Cell::new(0),
+            bytes: Cell::new(0),
+            live: Cell::new(0),
+            peak: Cell::new(0),
+        }
+    }
+
+    fn allocated(&self, size: usize) {
+        self.allocations.set(self.allocations.get() + 1);
+        self.bytes.set(self.bytes.get() + size as u64);
+        self.resize(size as i64);
+    }
+
+    fn resize(&self, change: i64) {
+        let live = self.live.get() + change;
+        self.live.set(live);
+        self.peak.set(self.peak.get().max(live));
+    }
+}
+
+thread_local! {
+    // Const-initialised with no destructor, so it is safe to touch from
+    // inside the allocator
+    static COUNTERS: Counters = const { Counters::new() };
+}
+
+fn with_counters(f: impl FnOnce(&Counters)) {
+    let _ = COUNTERS.try_with(f);
+}
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = unsafe { System.alloc(layout) };
+        if !ptr.is_null() {
+            with_counters(|c| c.allocated(layout.size()));
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = unsafe { System.alloc_zeroed(layout) };
+        if !ptr.is_null() {
+            with_counters(|c| c.allocated(layout.size()));
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) };
+        with_counters(|c| c.resize(-(layout.size() as i64)));
+    }
+
+    /// Counted as a fresh allocation of `new_size` bytes, the way a `Vec`
+    /// growing in place would have been without `realloc`.
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = unsafe { System.realloc(ptr, layout, new_size) };
+        if !new_ptr.is_null() {
+            with_counters(|c| {
+                c.allocations.set(c.allocations.get() + 1);
+                c.bytes.set(c.bytes.get() + new_size as u64);
+                c.resize(new_size as i64 - layout.size() as i64);
+            });
+        }
+        new_ptr
+    }
+}
+
+/// Allocations made while running one stage.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Allocs {
+    /// Number of allocations, including reallocations.
+    pub allocations: u64,
+    /// Total bytes requested.
+    pub bytes: u64,
+    /// Most bytes live at once, above what was live when the stage started.
+    pub peak_bytes: u64,
+}
+
+impl fmt::Display for Allocs {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} allocations, {} allocated, {} peak",
+            self.allocations,
+            format_bytes(self.bytes),
+            format_bytes(self.peak_bytes)
+        )
+    }
+}
+
+fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["B", "KiB", "MiB", "GiB"];
+    let mut value = bytes as f64;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    if unit == 0 {
+        format!("{} B", bytes)
+    } else {
+        format!("{:.1} {}", value, UNITS[unit])
+    }
+}
+
+/// Run `f` and count the allocations it makes on this thread. Everything is
+/// zero unless [`ENABLED`].
+pub fn count<T>(f: impl FnOnce() -> T) -> (T, Allocs) {
+    let mut before = (0, 0, 0);
+    with_counters(|c| {
+        // Measure the peak from here rather than since the thread started
+        c.peak.set(c.live.get());
+        before = (c.allocations.get(), c.bytes.get(), c.live.get());
+    });
+
+    let value = f();
+
+    let mut allocs = Allocs::default();
+    with_counters(|c| {
+        allocs = Allocs {
+            allocations: c.allocations.get() - before.0,
+            bytes: c.bytes.get() - before.1,
+            peak_bytes: (c.peak.get() - before.2).max(0) as u64,
+        };
+    });
+    (value, allocs)
+}
+
+/// Per-stage allocations for one day. Parts that were not selected have
+/// none.
+#[derive(Debug, Clone)]
+pub struct DayAllocs {
+    pub parse: Allocs,
+    pub part1: Option<Allocs>,
+    pub part2: Option<Allocs>,
+}
+
+impl DayAllocs {
+    pub fn part(&self, part: u8) -> Option<&Allocs> {
+        match part {
+            1 => self.part1.as_ref(),
+            2 => self.part2.as_ref(),
+            _ => None,
+        }
+    }
+}
+
+/// Parse once and solve each selected part once, counting each stage's
+/// allocations separately. Fails only if the input does not parse.
+pub fn count_day(solution: &dyn DynSolution, input: &str, parts: Parts) -> AocResult<DayAllocs> {
+    let (parsed, parse) = count(|| solution.parse(black_box(input)));
+    let parsed = parsed?;
+    let part1 = parts
+        .includes(1)
+        .then(|| count(|| black_box(solution.part1(parsed.as_ref()))).1);
+    let part2 = parts
+        .includes(2)
+        .then(|| count(|| black_box(solution.part2(parsed.as_ref()))).1);
+
+    Ok(DayAllocs { parse, part1, part2 })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn counters_track_totals_and_peak() {
+        let counters = Counters::new();
+        counters.allocated(100);
+        counters.allocated(50);
+        counters.resize(-100);
+        counters.allocated(10);
+        assert_eq!(counters.allocations.get(), 3);
+        assert_eq!(counters.bytes.get(), 160);
+        assert_eq!(counters.live.get(), 60);
+        assert_eq!(counters.peak.get(), 150);
+    }
+
+    #[test]
+    fn bytes_are_shown_in_binary_units() {
+        assert_eq!(format_bytes(512), "512 B");
+        assert_eq!(format_bytes(1536), "1.5 KiB");
+        assert_eq!(format_bytes(3 * 1024 * 1024), "3.0 MiB");
+    }
+
+    #[cfg(feature = "count-allocs")]
+    #[test]
+    fn count_sees_allocations_in_the_closure() {
+        let (v, allocs) = count(|| {
+            let scratch = vec![0u8; 4096];
+            drop(black_box(scratch));
+            vec![1u8; 1000]
+        });
+        assert_eq!(v.len(), 1000);
+        assert_eq!(allocs.allocations, 2);
+        assert_eq!(allocs.bytes, 5096);
+        assert_eq!(allocs.peak_bytes, 4096);
+    }
+
+    #[cfg(not(feature = "count-allocs"))]
+    #[test]
+    fn count_is_zero_without_the_feature() {
+        let (_, allocs) = count(|| vec![0u8; 1000]);
+        assert_eq!(allocs, Allocs::default());
+    }
+}
@@ -0,0 +1,227 @@
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+use toml_edit::DocumentMut;
+
+use crate::solution::Answer;
+
+/// Directory holding one `dayXX.toml` per day.
+pub const ANSWERS_DIR: &str = "answers";
+
+/// Table name used for the default `inputs/input_dayXX.txt` input.
+pub const DEFAULT_INPUT: &str = "input";
+
+/// Accepted answers for one input. Either part may still be unknown.
+///
+/// Values can be written as TOML integers or strings; they are compared
+/// as text against the computed [`Answer`].
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Expected {
+    #[serde(default, skip_serializing_if = "Option::is_none", with = "answer_value")]
+    pub part1: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none", with = "answer_value")]
+    pub part2: Option<String>,
+}
+
+impl Expected {
+    pub fn part(&self, part: u8) -> Option<&str> {
+        match part {
+            1 => self.part1.as_deref(),
+            2 => self.part2.as_deref(),
+            _ => None,
+        }
+    }
+}
+
+/// All expected answers for a day, keyed by input name.
+pub type DayAnswers = BTreeMap<String, Expected>;
+
+pub fn answers_path(day: u8) -> PathBuf {
+    PathBuf::from(ANSWERS_DIR).join(format!("day{:02}.toml", day))
+}
+
+/// Load a day's answers file. A missing file means nothing is known yet.
+pub fn load(day: u8) -> io::Result<DayAnswers> {
+    load_file(&answers_path(day))
+}
+
+pub fn load_file(path: &Path) -> io::Result<DayAnswers> {
+    let content = match fs::read_to_string(path) {
+        Ok(content) => content,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(DayAnswers::new()),
+        Err(e) => return Err(e),
+    };
+    toml::from_str(&content).map_err(|e| {
+        io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("{}: {}", path.display(), e),
+        )
+    })
+}
+
+/// Store `answer` as the accepted answer for one part of an input.
+///
+/// Only that one value is touched; comments, formatting and answers
+/// written as integers elsewhere in the file are kept as they are.
+pub fn record(path: &Path, input_name: &str, part: u8, answer: &str) -> io::Result<()> {
+    let key = match part {
+        1 => "part1",
+        2 => "part2",
+        _ => return Err(io::Error::new(io::ErrorKind::InvalidInput, format!("No part {}", part))),
+    };
+    let content = match fs::read_to_string(path) {
+        Ok(content) => content,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => String::new(),
+        Err(e) => return Err(e),
+    };
+    let invalid = |message: String| {
+        io::Error::new(io::ErrorKind::InvalidData, format!("{}: {}", path.display(), message))
+    };
+    let mut document: DocumentMut = content
+        .parse()
+        .map_err(|e: toml_edit::TomlError| invalid(e.to_string()))?;
+    let table = document
+        .entry(input_name)
+        .or_insert(toml_edit::table())
+        .as_table_like_mut()
+        .ok_or_else(|| invalid(format!("[{}] is not a table", input_name)))?;
+    table.insert(key, toml_edit::value(answer));
+
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(path, document.to_string())
+}
+
+/// Expected answers for one input of a day, if any are recorded.
+pub fn expected(day: u8, input_name: &str) -> io::Result<Expected> {
+    Ok(load(day)?.remove(input_name).unwrap_or_default())
+}
+
+/// Outcome of comparing a computed answer with the recorded one.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Pass,
+    Fail { expected: String },
+    Unknown,
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Verdict::Pass => write!(f, "PASS"),
+            Verdict::Fail { .. } => write!(f, "FAIL"),
+            Verdict::Unknown => write!(f, "UNKNOWN"),
+        }
+    }
+}
+
+pub fn check(expected: Option<&str>, actual: &Answer) -> Verdict {
+    match expected {
+        None => Verdict::Unknown,
+        Some(expected) if expected.trim() == actual.to_string() => Verdict::Pass,
+        Some(expected) => Verdict::Fail {
+            expected: expected.trim().to_string(),
+        },
+    }
+}
+
+/// Accept either `part1 = 123` or `part1 = "abc"` and always write strings.
+mod answer_value {
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Raw {
+        Int(i64),
+        Text(String),
+    }
+
+    pub fn serialize<S: Serializer>(value: &Option<String>, serializer: S) -> Result<S::Ok, S::Error> {
+        match value {
+            Some(v) => serializer.serialize_str(v),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<String>, D::Error> {
+        Ok(Option::<Raw>::deserialize(deserializer)?.map(|raw| match raw {
+            Raw::Int(n) => n.to_string(),
+            Raw::Text(s) => s,
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_integer_and_string_answers() {
+        let answers: DayAnswers = toml::from_str(
+            r#"
+            [input]
+            part1 = 1118
+            part2 = "6289"
+
+            [example]
+            part1 = 3
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(answers["input"].part(1), Some("1118"));
+        assert_eq!(answers["input"].part(2), Some("6289"));
+        assert_eq!(answers["example"].part(2), None);
+    }
+
+    #[test]
+    fn record_adds_answer_and_keeps_the_rest() {
+        let dir = std::env::temp_dir().join(format!("aoc2025-answers-{}", std::process::id()));
+        let path = dir.join("day07.toml");
+        let _ = fs::remove_dir_all(&dir);
+
+        record(&path, "input", 1, "42").unwrap();
+        record(&path, "example", 2, "7").unwrap();
+        record(&path, "input", 2, "abc").unwrap();
+
+        let answers = load_file(&path).unwrap();
+        assert_eq!(answers["input"].part(1), Some("42"));
+        assert_eq!(answers["input"].part(2), Some("abc"));
+        assert_eq!(answers["example"].part(1), None);
+        assert_eq!(answers["example"].part(2), Some("7"));
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn record_keeps_comments_and_integer_answers() {
+        let dir = std::env::temp_dir().join(format!("aoc2025-answers-edit-{}", std::process::id()));
+        let path = dir.join("day04.toml");
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(&path, "# From the puzzle text\n[example]\npart1 = 13 # emphasized\n").unwrap();
+
+        record(&path, "example", 2, "43").unwrap();
+        record(&path, "input", 1, "1118").unwrap();
+
+        let content = fs::read_to_string(&path).unwrap();
+        assert!(content.starts_with("# From the puzzle text\n[example]\npart1 = 13 # emphasized\n"), "{}", content);
+        let answers = load_file(&path).unwrap();
+        assert_eq!(answers["example"].part(2), Some("43"));
+        assert_eq!(answers["input"].part(1), Some("1118"));
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn check_verdicts() {
+        assert_eq!(check(Some("42"), &Answer::UInt(42)), Verdict::Pass);
+        assert_eq!(
+            check(Some("41"), &Answer::UInt(42)),
+            Verdict::Fail { expected: "41".to_string() }
+        );
+        assert_eq!(check(None, &Answer::UInt(42)), Verdict::Unknown);
+    }
+}
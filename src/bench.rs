@@ -0,0 +1,230 @@
+use std::fmt;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use crate::error::AocResult;
+use crate::runner::Parts;
+use crate::solution::DynSolution;
+
+/// Always collect at least this many samples, even when a single
+/// iteration blows the time budget.
+const MIN_SAMPLES: usize = 5;
+
+/// How many times to repeat each stage of a day.
+#[derive(Debug, Clone, Copy)]
+pub struct BenchConfig {
+    /// Untimed runs before sampling starts.
+    pub warmup: u32,
+    /// Fixed number of timed runs. Overrides `budget` when set.
+    pub iterations: Option<u32>,
+    /// Keep sampling until this much time has been spent in the stage.
+    pub budget: Duration,
+}
+
+/// Summary statistics over a set of timing samples.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Stats {
+    pub samples: usize,
+    pub min: Duration,
+    pub max: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub stddev: Duration,
+    /// Samples below `Q1 - 1.5 * IQR`.
+    pub low_outliers: usize,
+    /// Samples above `Q3 + 1.5 * IQR`.
+    pub high_outliers: usize,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &[Duration]) -> Stats {
+        assert!(!samples.is_empty(), "Cannot compute statistics without samples");
+
+        let mut sorted: Vec<f64> = samples.iter().map(Duration::as_secs_f64).collect();
+        sorted.sort_unstable_by(f64::total_cmp);
+
+        let n = sorted.len();
+        let mean = sorted.iter().sum::<f64>() / n as f64;
+        let variance = if n > 1 {
+            sorted.iter().map(|s| (s - mean).powi(2)).sum::<f64>() / (n - 1) as f64
+        } else {
+            0.0
+        };
+
+        // Tukey's fences for outlier detection
+        let q1 = quantile(&sorted, 0.25);
+        let q3 = quantile(&sorted, 0.75);
+        let iqr = q3 - q1;
+        let low_fence = q1 - 1.5 * iqr;
+        let high_fence = q3 + 1.5 * iqr;
+
+        Stats {
+            samples: n,
+            min: Duration::from_secs_f64(sorted[0]),
+            max: Duration::from_secs_f64(sorted[n - 1]),
+            median: Duration::from_secs_f64(quantile(&sorted, 0.5)),
+            mean: Duration::from_secs_f64(mean),
+            stddev: Duration::from_secs_f64(variance.sqrt()),
+            low_outliers: sorted.iter().filter(|&&s| s < low_fence).count(),
+            high_outliers: sorted.iter().filter(|&&s| s > high_fence).count(),
+        }
+    }
+}
+
+impl fmt::Display for Stats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "min {:.2?}  median {:.2?}  mean {:.2?} ± {:.2?}  ({} samples",
+            self.min, self.median, self.mean, self.stddev, self.samples
+        )?;
+        let outliers = self.low_outliers + self.high_outliers;
+        if outliers > 0 {
+            write!(
+                f,
+                ", {} outliers: {} low, {} high",
+                outliers, self.low_outliers, self.high_outliers
+            )?;
+        }
+        write!(f, ")")
+    }
+}
+
+/// Linearly interpolated quantile of an already sorted slice.
+fn quantile(sorted: &[f64], q: f64) -> f64 {
+    let pos = q * (sorted.len() - 1) as f64;
+    let lower = pos.floor() as usize;
+    let upper = pos.ceil() as usize;
+    let weight = pos - lower as f64;
+    sorted[lower] * (1.0 - weight) + sorted[upper] * weight
+}
+
+/// Warm up, then repeatedly time `f` according to `config`.
+pub fn measure<T>(config: &BenchConfig, mut f: impl FnMut() -> T) -> Stats {
+    for _ in 0..config.warmup {
+        black_box(f());
+    }
+
+    let mut samples = Vec::new();
+    let mut spent = Duration::ZERO;
+    loop {
+        let done = match config.iterations {
+            Some(n) => samples.len() >= n.max(1) as usize,
+            None => samples.len() >= MIN_SAMPLES && spent >= config.budget,
+        };
+        if done {
+            break;
+        }
+
+        let start = Instant::now();
+        black_box(f());
+        let elapsed = start.elapsed();
+        spent += elapsed;
+        samples.push(elapsed);
+    }
+
+    Stats::from_samples(&samples)
+}
+
+/// Per-stage statistics for one day. Parts that were not selected have
+/// none.
+#[derive(Debug, Clone)]
+pub struct DayStats {
+    pub day: u8,
+    pub parse: Stats,
+    pub part1: Option<Stats>,
+    pub part2: Option<Stats>,
+}
+
+impl DayStats {
+    pub fn part(&self, part: u8) -> Option<&Stats> {
+        match part {
+            1 => self.part1.as_ref(),
+            2 => self.part2.as_ref(),
+            _ => None,
+        }
+    }
+}
+
+/// Benchmark parsing and each selected part of a day independently. Fails
+/// only if the input does not parse.
+pub fn bench_day(
+    solution: &dyn DynSolution,
+    input: &str,
+    config: &BenchConfig,
+    parts: Parts,
+) -> AocResult<DayStats> {
+    let parse = measure(config, || solution.parse(black_box(input)));
+
+    let parsed = solution.parse(input)?;
+    let part1 = parts
+        .includes(1)
+        .then(|| measure(config, || solution.part1(black_box(parsed.as_ref()))));
+    let part2 = parts
+        .includes(2)
+        .then(|| measure(config, || solution.part2(black_box(parsed.as_ref()))));
+
+    Ok(DayStats {
+        day: solution.day(),
+        parse,
+        part1,
+        part2,
+    })
+}
+
+/// Warn when timings are being taken from an unoptimised build.
+pub fn warn_if_unoptimized() {
+    if cfg!(debug_assertions) {
+        eprintln!(
+            "warning: benchmarking a debug build; timings will be 10-100x slower. \
+             Re-run with `cargo run --release`."
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ms(values: &[u64]) -> Vec<Duration> {
+        values.iter().map(|&v| Duration::from_millis(v)).collect()
+    }
+
+    #[test]
+    fn stats_basic_summary() {
+        let stats = Stats::from_samples(&ms(&[4, 1, 3, 2, 5]));
+        assert_eq!(stats.samples, 5);
+        assert_eq!(stats.min, Duration::from_millis(1));
+        assert_eq!(stats.max, Duration::from_millis(5));
+        assert_eq!(stats.median, Duration::from_millis(3));
+        assert_eq!(stats.mean, Duration::from_millis(3));
+        assert_eq!(stats.low_outliers + stats.high_outliers, 0);
+    }
+
+    #[test]
+    fn stats_even_count_median_interpolates() {
+        let stats = Stats::from_samples(&ms(&[1, 2, 3, 4]));
+        assert_eq!(stats.median, Duration::from_micros(2500));
+    }
+
+    #[test]
+    fn stats_detects_outliers() {
+        // Q1 = 10, Q3 = 10, so anything away from 10 is an outlier
+        let stats = Stats::from_samples(&ms(&[10, 10, 10, 10, 10, 10, 1, 100]));
+        assert_eq!(stats.low_outliers, 1);
+        assert_eq!(stats.high_outliers, 1);
+    }
+
+    #[test]
+    fn measure_fixed_iterations() {
+        let config = BenchConfig {
+            warmup: 2,
+            iterations: Some(7),
+            budget: Duration::from_secs(60),
+        };
+        let mut calls = 0;
+        let stats = measure(&config, || calls += 1);
+        assert_eq!(stats.samples, 7);
+        assert_eq!(calls, 9);
+    }
+}
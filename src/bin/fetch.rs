@@ -0,0 +1,41 @@
+use clap::Parser;
+use std::process;
+
+use aoc2025::client::{self, Client, Fetched};
+use aoc2025::runner;
+
+/// Download a day's puzzle input to inputs/input_dayXX.txt.
+///
+/// The session token comes from AOC_SESSION or `session` in aoc.toml.
+/// Inputs that are already present are never downloaded again.
+#[derive(Parser)]
+struct Args {
+    #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: u8,
+
+    /// Server to download from instead of adventofcode.com (or AOC_BASE_URL)
+    #[arg(long)]
+    base_url: Option<String>,
+}
+
+fn main() {
+    let args = Args::parse();
+
+    let path = runner::input_path(args.day);
+    // A cached input needs no session, so don't insist on one
+    let result = if client::is_cached(&path) {
+        Ok(Fetched::Cached(path))
+    } else {
+        Client::from_env(args.base_url.as_deref())
+            .and_then(|client| client::fetch_input(&client, args.day, &path))
+    };
+
+    match result {
+        Ok(Fetched::Downloaded(path)) => println!("Downloaded {}", path.display()),
+        Ok(Fetched::Cached(path)) => println!("{} already exists; not downloading again", path.display()),
+        Err(e) => {
+            eprintln!("Failed to fetch day {}: {}", args.day, e);
+            process::exit(1);
+        }
+    }
+}
@@ -0,0 +1,78 @@
+use clap::Parser;
+use std::fs;
+use std::process;
+
+use aoc2025::answers;
+use aoc2025::client::Client;
+use aoc2025::puzzle;
+use aoc2025::runner::{self, InputSource};
+
+/// Download a day's puzzle description to puzzles/dayXX.md and extract its
+/// examples.
+///
+/// Each `<pre><code>` block becomes inputs/example_dayXX_N.txt, and the
+/// emphasized answers are recorded as the `[example_1]` answers. Existing
+/// example files and answers are left alone.
+#[derive(Parser)]
+struct Args {
+    #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: u8,
+
+    /// Server to download from instead of adventofcode.com (or AOC_BASE_URL)
+    #[arg(long)]
+    base_url: Option<String>,
+}
+
+fn fail(message: impl std::fmt::Display) -> ! {
+    eprintln!("{}", message);
+    process::exit(1);
+}
+
+fn main() {
+    let args = Args::parse();
+    let day = args.day;
+
+    let html = Client::from_env(args.base_url.as_deref())
+        .and_then(|client| client.get(&client.day_url(day, "")))
+        .unwrap_or_else(|e| fail(format!("Failed to download day {}: {}", day, e)));
+
+    let puzzle = puzzle::parse_page(&html);
+    if puzzle.markdown.trim().is_empty() {
+        fail(format!("No puzzle description found on the day {} page", day));
+    }
+
+    // 1. The description, refreshed every time since part 2 appears later
+    let path = puzzle::puzzle_path(day);
+    fs::create_dir_all(puzzle::PUZZLES_DIR)
+        .and_then(|()| fs::write(&path, &puzzle.markdown))
+        .unwrap_or_else(|e| fail(format!("Failed to write {}: {}", path.display(), e)));
+    println!("Wrote {}", path.display());
+
+    // 2. Example inputs
+    for (i, example) in puzzle.examples.iter().enumerate() {
+        let path = runner::example_path(day, Some(i as u32 + 1));
+        if path.exists() {
+            println!("Kept existing {}", path.display());
+        } else {
+            path.parent()
+                .map_or(Ok(()), fs::create_dir_all)
+                .and_then(|()| fs::write(&path, example))
+                .unwrap_or_else(|e| fail(format!("Failed to write {}: {}", path.display(), e)));
+            println!("Created {}", path.display());
+        }
+    }
+
+    // 3. Example answers, assumed to belong to the first example
+    let key = InputSource::File(runner::example_path(day, Some(1)))
+        .answers_key(day)
+        .expect("Example paths have a file stem");
+    let answers_path = answers::answers_path(day);
+    let known = answers::expected(day, &key).unwrap_or_else(|e| fail(e));
+    for part in [1, 2] {
+        let (Some(answer), None) = (puzzle.example_answers.part(part), known.part(part)) else {
+            continue;
+        };
+        answers::record(&answers_path, &key, part, answer).unwrap_or_else(|e| fail(e));
+        println!("Recorded [{}] part {} = {} in {}", key, part, answer, answers_path.display());
+    }
+}
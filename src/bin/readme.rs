@@ -0,0 +1,189 @@
+use clap::Parser;
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::env;
+use std::fs;
+use std::process::{self, Command};
+use std::time::Duration;
+
+const START_MARKER: &str = "<!-- RESULTS:START -->";
+const END_MARKER: &str = "<!-- RESULTS:END -->";
+
+/// Run every implemented day in release mode and rewrite the results table
+/// in README.md.
+#[derive(Parser)]
+struct Args {
+    /// README file to update
+    #[arg(long, default_value = "README.md")]
+    readme: String,
+
+    /// Time budget per stage passed to `--stats`, e.g. 500ms
+    #[arg(long, default_value = "500ms")]
+    bench_time: String,
+
+    /// Print the table instead of writing the README
+    #[arg(long)]
+    dry_run: bool,
+}
+
+/// The subset of `aoc2025 --format json` output used for the table.
+#[derive(Deserialize)]
+struct PartResult {
+    day: u8,
+    part: u8,
+    status: String,
+    timings: Option<Timings>,
+    stats: Option<Stats>,
+}
+
+#[derive(Deserialize)]
+struct Timings {
+    parse_ns: u64,
+    solve_ns: u64,
+}
+
+#[derive(Deserialize)]
+struct Stats {
+    parse: StageStats,
+    solve: StageStats,
+}
+
+#[derive(Deserialize)]
+struct StageStats {
+    median_ns: u64,
+}
+
+impl PartResult {
+    /// Median under `--stats`, falling back to the single measured run.
+    fn parse_time(&self) -> Option<Duration> {
+        self.stats
+            .as_ref()
+            .map(|s| s.parse.median_ns)
+            .or_else(|| self.timings.as_ref().map(|t| t.parse_ns))
+            .map(Duration::from_nanos)
+    }
+
+    fn solve_time(&self) -> Option<Duration> {
+        self.stats
+            .as_ref()
+            .map(|s| s.solve.median_ns)
+            .or_else(|| self.timings.as_ref().map(|t| t.solve_ns))
+            .map(Duration::from_nanos)
+    }
+}
+
+fn run_all_days(bench_time: &str) -> Vec<PartResult> {
+    let cargo = env::var("CARGO").unwrap_or_else(|_| "cargo".to_string());
+    println!("Running all days in release mode...");
+    let output = Command::new(cargo)
+        .args(["run", "--release", "--quiet", "--bin", "aoc2025", "--"])
+        .args(["--all", "--stats", "--bench-time", bench_time])
+        .args(["--format", "json", "--no-history", "--color", "never"])
+        .output()
+        .expect("Failed to run cargo");
+
+    // A failing day still produces JSON; only give up if there is none
+    serde_json::from_slice(&output.stdout).unwrap_or_else(|e| {
+        eprintln!("{}", String::from_utf8_lossy(&output.stderr));
+        eprintln!("Failed to parse aoc2025 output: {}", e);
+        process::exit(1);
+    })
+}
+
+fn format_time(time: Option<Duration>) -> String {
+    time.map_or_else(|| "-".to_string(), |t| format!("{:.2?}", t))
+}
+
+fn render_table(results: &[PartResult]) -> String {
+    let mut days: BTreeMap<u8, Vec<&PartResult>> = BTreeMap::new();
+    for result in results {
+        days.entry(result.day).or_default().push(result);
+    }
+
+    let mut table = String::from(
+        "| Day | Stars | Parse | Part 1 | Part 2 |\n|----:|:-----:|------:|-------:|-------:|\n",
+    );
+    for (day, parts) in days {
+        let part = |n: u8| parts.iter().find(|p| p.part == n);
+        let stars = parts.iter().filter(|p| p.status == "pass").count();
+        let stars = if stars == 0 { "-".to_string() } else { "⭐".repeat(stars) };
+        table.push_str(&format!(
+            "| [{}](src/day{:02}.rs) | {} | {} | {} | {} |\n",
+            day,
+            day,
+            stars,
+            format_time(part(1).and_then(|p| p.parse_time())),
+            format_time(part(1).and_then(|p| p.solve_time())),
+            format_time(part(2).and_then(|p| p.solve_time())),
+        ));
+    }
+    table
+}
+
+/// Replace everything between the markers, keeping the markers themselves.
+fn replace_section(readme: &str, content: &str) -> Result<String, String> {
+    let start = readme
+        .find(START_MARKER)
+        .ok_or_else(|| format!("Missing {} marker", START_MARKER))?
+        + START_MARKER.len();
+    let end = readme[start..]
+        .find(END_MARKER)
+        .ok_or_else(|| format!("Missing {} marker after {}", END_MARKER, START_MARKER))?
+        + start;
+
+    Ok(format!("{}\n{}{}", &readme[..start], content, &readme[end..]))
+}
+
+fn main() {
+    let args = Args::parse();
+
+    let results = run_all_days(&args.bench_time);
+    let table = render_table(&results);
+
+    if args.dry_run {
+        print!("{}", table);
+        return;
+    }
+
+    let readme = fs::read_to_string(&args.readme).expect("Failed to read README");
+    let updated = replace_section(&readme, &table).unwrap_or_else(|e| {
+        eprintln!("{}: {}", args.readme, e);
+        process::exit(1);
+    });
+    fs::write(&args.readme, updated).expect("Failed to write README");
+    println!("Updated results table in {}", args.readme);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn replace_section_keeps_markers_and_surroundings() {
+        let readme = format!("intro\n{}\nold table\n{}\noutro\n", START_MARKER, END_MARKER);
+        let updated = replace_section(&readme, "new table\n").unwrap();
+        assert_eq!(
+            updated,
+            format!("intro\n{}\nnew table\n{}\noutro\n", START_MARKER, END_MARKER)
+        );
+        assert!(replace_section("no markers", "x").is_err());
+    }
+
+    #[test]
+    fn render_table_counts_passing_parts_as_stars() {
+        let results: Vec<PartResult> = serde_json::from_str(
+            r#"[
+                {"day":1,"part":1,"status":"pass","timings":{"parse_ns":1000,"solve_ns":2000000}},
+                {"day":1,"part":2,"status":"unknown","timings":{"parse_ns":1000,"solve_ns":3000}},
+                {"day":3,"part":1,"status":"error"},
+                {"day":3,"part":2,"status":"error"}
+            ]"#,
+        )
+        .unwrap();
+
+        let table = render_table(&results);
+        let rows: Vec<&str> = table.lines().collect();
+        assert_eq!(rows[2], "| [1](src/day01.rs) | ⭐ | 1.00µs | 2.00ms | 3.00µs |");
+        assert_eq!(rows[3], "| [3](src/day03.rs) | - | - | - | - |");
+    }
+}
@@ -0,0 +1,79 @@
+use clap::Parser;
+use std::path::Path;
+use std::process;
+
+use aoc2025::answers;
+use aoc2025::client::Client;
+use aoc2025::runner::{self, Input, InputSource, Parts};
+use aoc2025::submit::{self, Reply, Stores};
+
+/// Solve one part of a day on its puzzle input and submit the answer.
+///
+/// Every submission is logged to submissions.jsonl. Answers already
+/// rejected, ruled out by an earlier too high / too low, or sent during
+/// the site's cooldown are refused without contacting the server.
+#[derive(Parser)]
+struct Args {
+    #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: u8,
+
+    #[arg(value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: u8,
+
+    /// Submit this answer instead of computing it
+    #[arg(long)]
+    answer: Option<String>,
+
+    /// Server to submit to instead of adventofcode.com (or AOC_BASE_URL)
+    #[arg(long)]
+    base_url: Option<String>,
+}
+
+/// Run the requested part on the day's puzzle input.
+fn compute_answer(day: u8, part: u8) -> Result<String, String> {
+    let solution = aoc2025::find_solution(day)
+        .ok_or_else(|| format!("Day {} not implemented (no src/day{:02}.rs)", day, day))?;
+    let input = Input::read(InputSource::default_for(day)).map_err(|e| e.to_string())?;
+    let result = runner::run_day(solution, &input, None, Parts::Only(part));
+
+    let (_, outcome, _) = result.parts()[0];
+    outcome
+        .as_ref()
+        .map(|answer| answer.to_string())
+        .map_err(|failure| failure.to_string())
+}
+
+fn fail(message: impl std::fmt::Display) -> ! {
+    eprintln!("{}", message);
+    process::exit(1);
+}
+
+fn main() {
+    let args = Args::parse();
+
+    let client = Client::from_env(args.base_url.as_deref()).unwrap_or_else(|e| fail(e));
+
+    let answer = match args.answer {
+        Some(answer) => answer.trim().to_string(),
+        None => compute_answer(args.day, args.part).unwrap_or_else(|e| fail(e)),
+    };
+    println!("Submitting day {} part {}: {}", args.day, args.part, answer);
+
+    let answers_path = answers::answers_path(args.day);
+    let stores = Stores {
+        log: Path::new(submit::SUBMISSIONS_PATH),
+        answers: &answers_path,
+    };
+    let response = submit::submit(&client, &stores, args.day, args.part, &answer).unwrap_or_else(|e| fail(e));
+
+    println!("{}", response.message);
+    match response.wait {
+        Some(wait) => println!("Answer was {}; wait {}s before the next one", response.reply, wait.as_secs()),
+        None => println!("Answer was {}", response.reply),
+    }
+    if response.reply == Reply::Correct {
+        println!("Recorded in {}", answers_path.display());
+    } else {
+        process::exit(1);
+    }
+}
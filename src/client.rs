@@ -0,0 +1,348 @@
+use std::env;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use serde::Deserialize;
+use ureq::Agent;
+
+/// Puzzle year; every URL is under `{base_url}/2025/`.
+pub const YEAR: u16 = 2025;
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// Local settings, never committed: `session`, `base_url` and `contact`.
+pub const CONFIG_PATH: &str = "aoc.toml";
+
+pub const SESSION_ENV: &str = "AOC_SESSION";
+pub const BASE_URL_ENV: &str = "AOC_BASE_URL";
+pub const CONTACT_ENV: &str = "AOC_CONTACT";
+
+/// Contents of [`CONFIG_PATH`]. Environment variables take precedence.
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Config {
+    /// Value of the `session` cookie from a logged-in browser.
+    pub session: Option<String>,
+    /// Server to talk to instead of [`DEFAULT_BASE_URL`].
+    pub base_url: Option<String>,
+    /// Email or URL added to the User-Agent so the site can reach us.
+    pub contact: Option<String>,
+}
+
+impl Config {
+    /// Load a config file. A missing file is an empty config.
+    pub fn load(path: &Path) -> io::Result<Config> {
+        let content = match fs::read_to_string(path) {
+            Ok(content) => content,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Config::default()),
+            Err(e) => return Err(e),
+        };
+        toml::from_str(&content).map_err(|e| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("{}: {}", path.display(), e),
+            )
+        })
+    }
+
+    /// [`CONFIG_PATH`] with `AOC_SESSION`, `AOC_BASE_URL` and
+    /// `AOC_CONTACT` applied on top.
+    pub fn from_env() -> io::Result<Config> {
+        let mut config = Config::load(Path::new(CONFIG_PATH))?;
+        let var = |name| env::var(name).ok().filter(|v| !v.trim().is_empty());
+        config.session = var(SESSION_ENV).or(config.session);
+        config.base_url = var(BASE_URL_ENV).or(config.base_url);
+        config.contact = var(CONTACT_ENV).or(config.contact);
+        Ok(config)
+    }
+}
+
+/// Why a request to the puzzle site failed.
+#[derive(Debug)]
+pub enum ClientError {
+    /// No session token in the environment or config file.
+    NoSession,
+    /// The server answered with something other than 200 OK.
+    Status { url: String, status: u16, body: String },
+    Http(ureq::Error),
+    Io(io::Error),
+}
+
+impl fmt::Display for ClientError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ClientError::NoSession => write!(
+                f,
+                "No session token: set {} or `session` in {}",
+                SESSION_ENV, CONFIG_PATH
+            ),
+            ClientError::Status { url, status, body } => {
+                write!(f, "{} returned HTTP {}: {}", url, status, body.trim())
+            }
+            ClientError::Http(e) => write!(f, "Request failed: {}", e),
+            ClientError::Io(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl std::error::Error for ClientError {}
+
+impl From<ureq::Error> for ClientError {
+    fn from(e: ureq::Error) -> Self {
+        ClientError::Http(e)
+    }
+}
+
+impl From<io::Error> for ClientError {
+    fn from(e: io::Error) -> Self {
+        ClientError::Io(e)
+    }
+}
+
+/// Authenticated access to the puzzle site.
+pub struct Client {
+    base_url: String,
+    session: String,
+    user_agent: String,
+    agent: Agent,
+}
+
+impl Client {
+    pub fn new(base_url: &str, session: &str, contact: Option<&str>) -> Client {
+        let agent = Agent::config_builder()
+            .http_status_as_error(false)
+            .timeout_global(Some(Duration::from_secs(30)))
+            .build()
+            .into();
+        let user_agent = match contact {
+            Some(contact) => format!("aoc2025/{} (+{})", env!("CARGO_PKG_VERSION"), contact),
+            None => format!("aoc2025/{}", env!("CARGO_PKG_VERSION")),
+        };
+        Client {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.trim().trim_start_matches("session=").to_string(),
+            user_agent,
+            agent,
+        }
+    }
+
+    /// A client for `config`, with `base_url` overriding the configured one.
+    pub fn from_config(config: &Config, base_url: Option<&str>) -> Result<Client, ClientError> {
+        let session = config.session.as_deref().ok_or(ClientError::NoSession)?;
+        let base_url = base_url
+            .or(config.base_url.as_deref())
+            .unwrap_or(DEFAULT_BASE_URL);
+        Ok(Client::new(base_url, session, config.contact.as_deref()))
+    }
+
+    /// A client for [`Config::from_env`], with `base_url` overriding the
+    /// configured one.
+    pub fn from_env(base_url: Option<&str>) -> Result<Client, ClientError> {
+        let config = Config::from_env().map_err(ClientError::Io)?;
+        Client::from_config(&config, base_url)
+    }
+
+    /// URL of a page for `day`, e.g. `day_url(5, "/input")`.
+    pub fn day_url(&self, day: u8, suffix: &str) -> String {
+        format!("{}/{}/day/{}{}", self.base_url, YEAR, day, suffix)
+    }
+
+    /// GET a page and return its body.
+    pub fn get(&self, url: &str) -> Result<String, ClientError> {
+        let response = self
+            .agent
+            .get(url)
+            .header("Cookie", &format!("session={}", self.session))
+            .header("User-Agent", &self.user_agent)
+            .call()?;
+        read_body(url, response)
+    }
+
+    /// POST a form and return the response body.
+    pub fn post_form(&self, url: &str, form: &[(&str, &str)]) -> Result<String, ClientError> {
+        let response = self
+            .agent
+            .post(url)
+            .header("Cookie", &format!("session={}", self.session))
+            .header("User-Agent", &self.user_agent)
+            .send_form(form.iter().copied())?;
+        read_body(url, response)
+    }
+
+    /// Download the puzzle input for `day`.
+    pub fn input(&self, day: u8) -> Result<String, ClientError> {
+        self.get(&self.day_url(day, "/input"))
+    }
+}
+
+fn read_body(url: &str, mut response: ureq::http::Response<ureq::Body>) -> Result<String, ClientError> {
+    let status = response.status().as_u16();
+    let body = response.body_mut().read_to_string()?;
+    if status == 200 {
+        Ok(body)
+    } else {
+        Err(ClientError::Status {
+            url: url.to_string(),
+            status,
+            body,
+        })
+    }
+}
+
+/// Result of [`fetch_input`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Fetched {
+    /// The file was already there and was left alone.
+    Cached(PathBuf),
+    Downloaded(PathBuf),
+}
+
+/// Whether `path` already holds an input. Inputs never change, so a
+/// non-empty file is never downloaded again; an empty file, as left by
+/// `scaffold`, is not an input yet.
+pub fn is_cached(path: &Path) -> bool {
+    fs::metadata(path).is_ok_and(|m| m.len() > 0)
+}
+
+/// Download `day`'s input to `path` unless it is already [cached](is_cached).
+pub fn fetch_input(client: &Client, day: u8, path: &Path) -> Result<Fetched, ClientError> {
+    if is_cached(path) {
+        return Ok(Fetched::Cached(path.to_path_buf()));
+    }
+
+    let input = client.input(day)?;
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(path, input)?;
+    Ok(Fetched::Downloaded(path.to_path_buf()))
+}
+
+/// A one-connection-at-a-time HTTP server for tests that records each
+/// request and answers from a script.
+#[cfg(test)]
+pub(crate) mod test_server {
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::TcpListener;
+    use std::sync::mpsc::{self, Receiver};
+    use std::thread;
+
+    /// Request line, headers and body of one received request.
+    #[derive(Debug, Clone)]
+    pub struct Request {
+        pub line: String,
+        pub headers: Vec<(String, String)>,
+        pub body: String,
+    }
+
+    impl Request {
+        pub fn header(&self, name: &str) -> Option<&str> {
+            self.headers
+                .iter()
+                .find(|(k, _)| k.eq_ignore_ascii_case(name))
+                .map(|(_, v)| v.as_str())
+        }
+    }
+
+    /// Serve each `(status, body)` in turn, then stop. Returns the base URL
+    /// and a receiver of the requests seen.
+    pub fn serve(responses: Vec<(u16, String)>) -> (String, Receiver<Request>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let (tx, rx) = mpsc::channel();
+
+        thread::spawn(move || {
+            for (status, body) in responses {
+                let Ok((stream, _)) = listener.accept() else { return };
+                let mut reader = BufReader::new(stream);
+
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                let mut headers = Vec::new();
+                loop {
+                    let mut header = String::new();
+                    reader.read_line(&mut header).unwrap();
+                    let Some((name, value)) = header.trim_end().split_once(':') else { break };
+                    headers.push((name.trim().to_string(), value.trim().to_string()));
+                }
+                let length = headers
+                    .iter()
+                    .find(|(k, _)| k.eq_ignore_ascii_case("content-length"))
+                    .map_or(0, |(_, v)| v.parse().unwrap());
+                let mut request_body = vec![0; length];
+                reader.read_exact(&mut request_body).unwrap();
+
+                let _ = tx.send(Request {
+                    line: line.trim_end().to_string(),
+                    headers,
+                    body: String::from_utf8(request_body).unwrap(),
+                });
+
+                let mut stream = reader.into_inner();
+                write!(
+                    stream,
+                    "HTTP/1.1 {} X\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    status,
+                    body.len(),
+                    body
+                )
+                .unwrap();
+            }
+        });
+
+        (base_url, rx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_path(name: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("aoc2025-client-{}-{}", std::process::id(), name));
+        let _ = fs::remove_dir_all(&dir);
+        dir.join("input_day03.txt")
+    }
+
+    #[test]
+    fn fetch_input_downloads_once_with_session_and_user_agent() {
+        let (base_url, requests) = test_server::serve(vec![(200, "1\n2\n3\n".to_string())]);
+        let client = Client::new(&base_url, "abc123\n", Some("me@example.com"));
+        let path = temp_path("fetch");
+
+        assert_eq!(fetch_input(&client, 3, &path).unwrap(), Fetched::Downloaded(path.clone()));
+        assert_eq!(fs::read_to_string(&path).unwrap(), "1\n2\n3\n");
+
+        let request = requests.recv().unwrap();
+        assert_eq!(request.line, "GET /2025/day/3/input HTTP/1.1");
+        assert_eq!(request.header("cookie"), Some("session=abc123"));
+        assert_eq!(
+            request.header("user-agent"),
+            Some(concat!("aoc2025/", env!("CARGO_PKG_VERSION"), " (+me@example.com)"))
+        );
+
+        // The server only answers once; a second fetch must not ask again
+        assert_eq!(fetch_input(&client, 3, &path).unwrap(), Fetched::Cached(path.clone()));
+        fs::remove_dir_all(path.parent().unwrap()).unwrap();
+    }
+
+    #[test]
+    fn fetch_input_reports_errors_without_writing() {
+        let (base_url, _requests) = test_server::serve(vec![(404, "Not found".to_string())]);
+        let client = Client::new(&base_url, "abc123", None);
+        let path = temp_path("missing");
+
+        let error = fetch_input(&client, 3, &path).unwrap_err();
+        assert!(matches!(error, ClientError::Status { status: 404, .. }), "{}", error);
+        assert!(!path.exists());
+    }
+
+    #[test]
+    fn config_requires_session() {
+        let config: Config = toml::from_str(r#"base_url = "http://localhost:1""#).unwrap();
+        assert!(matches!(Client::from_config(&config, None), Err(ClientError::NoSession)));
+    }
+}
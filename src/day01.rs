@@ -1,38 +1,64 @@
-use std::fs;
-
-pub fn run() {
-    println!("Running Day 01 challenge...");
-    // Add your Day 01 challenge code here
-    let input = fs::read_to_string("inputs/input_day01.txt").expect("Failed to read input file");
-    let directions: Vec<i16> = input.lines().map(parse).collect();
-    println!("Solution Part 1: {}", solve(&directions, &50, &100));
-    println!("Solution Part 2: {}", solve_part2(&directions, &50, &100));
-}  
-
-fn parse(line: &str) -> i16 {
+use crate::error::{AocError, AocResult};
+use crate::solution::{Answer, Solution};
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+    type Input = Vec<i16>;
+
+    fn parse(&self, input: &str) -> AocResult<Self::Input> {
+        input
+            .lines()
+            .enumerate()
+            .map(|(i, line)| parse(line).map_err(|e| e.on_line(i + 1)))
+            .collect()
+    }
+
+    fn part1(&self, directions: &Self::Input) -> AocResult<Answer> {
+        Ok(solve(directions, &50, &100)?.into())
+    }
+
+    fn part2(&self, directions: &Self::Input) -> AocResult<Answer> {
+        Ok(solve_part2(directions, &50, &100).into())
+    }
+}
+
+pub fn parse(line: &str) -> AocResult<i16> {
     // Ensure the line is not empty
-    assert!(!line.is_empty(), "Empty line in input");
+    let dir = line.chars().next().ok_or_else(|| AocError::new("Empty line in input"))?;
 
     // First character must be L or R
-    let dir = line.chars().next().expect("Missing direction");
-    assert!(dir == 'L' || dir == 'R', "Invalid direction: {}", dir);
-    let magnitude: u16 = line[1..].parse().expect("Invalid Number or out of i8 range");
-    
-
-    match dir {
-        'L' => -(magnitude as i16),
-        'R' => magnitude as i16,
-        // Note: unreachable as other directions are thrown as Invalid
-        _ => unreachable!(),
+    if dir != 'L' && dir != 'R' {
+        return Err(AocError::new(format!("Invalid direction: {:?}", dir)).at_column(1));
+    }
+    let digits = &line[1..];
+    if let Some(bad) = digits.find(|c: char| !c.is_ascii_digit()).or(digits.is_empty().then_some(0)) {
+        // Point at the first character that isn't a digit
+        return Err(AocError::new(format!("Invalid number: {:?}", digits)).at_column(2 + bad));
     }
+    // Parsing straight into i16 keeps large turns from wrapping around
+    let magnitude: i16 = digits.parse().map_err(|_| {
+        AocError::new(format!("Value {} is out of range (at most {})", digits, i16::MAX)).at_column(2)
+    })?;
+
+    Ok(match dir {
+        'L' => -magnitude,
+        _ => magnitude,
+    })
 }
 
 
 
-fn solve(input: &[i16], start_position: &u16, dial_size: &u16) -> u16 {
+pub fn solve(input: &[i16], start_position: &u16, dial_size: &u16) -> AocResult<u16> {
     // Count how many times the dial is at zero
     // start position should be between 0 and dial_size - 1
-    assert!(start_position < dial_size, "Start position ({}) must be less than dial size ({})", start_position, dial_size);
+    if start_position >= dial_size {
+        return Err(AocError::new(format!(
+            "Start position ({}) must be less than dial size ({})",
+            start_position, dial_size
+        )));
+    }
 
     // Initialize counters
     let mut cumulative_sum: i16 = *start_position as i16;
@@ -48,11 +74,11 @@ fn solve(input: &[i16], start_position: &u16, dial_size: &u16) -> u16 {
             zero_count += 1;
         }
     }
-    
-    return zero_count;
+
+    Ok(zero_count)
 }
 
-fn solve_part2(input: &[i16], start_position: &u16, dial_size: &u16) -> u16 {
+pub fn solve_part2(input: &[i16], start_position: &u16, dial_size: &u16) -> u16 {
     let d = *dial_size as i16;
     let mut unwrapped_pos: i16 = *start_position as i16;
     let mut zero_count: u16 = 0;
@@ -67,7 +93,7 @@ fn solve_part2(input: &[i16], start_position: &u16, dial_size: &u16) -> u16 {
 
         unwrapped_pos = end_pos.rem_euclid(d);
 
-        let mut crosses = (k_end - k_start).abs() as u16;
+        let mut crosses = (k_end - k_start).unsigned_abs();
 
         // Adjust for edge cases where we start or end exactly on zero or don't "cross" zero
         if crosses > 0 && start_pos == 0 && value < 0{
@@ -83,7 +109,7 @@ fn solve_part2(input: &[i16], start_position: &u16, dial_size: &u16) -> u16 {
         zero_count += crosses;
     }
 
-    return zero_count;
+    zero_count
 }
 
 
@@ -93,18 +119,40 @@ mod tests {
 
     #[test]
     fn parse_examples() {
-        assert_eq!(parse("L5"), -5);
-        assert_eq!(parse("R10"), 10);
-        assert_eq!(parse("L0"), 0);
-        assert_eq!(parse("R100"), 100);
+        assert_eq!(parse("L5"), Ok(-5));
+        assert_eq!(parse("R10"), Ok(10));
+        assert_eq!(parse("L0"), Ok(0));
+        assert_eq!(parse("R100"), Ok(100));
+    }
+
+    #[test]
+    fn parse_reports_position_of_bad_input() {
+        assert_eq!(parse("X5").unwrap_err().column, Some(1));
+        assert_eq!(parse("L5x").unwrap_err().column, Some(3));
+        assert_eq!(parse("R").unwrap_err().column, Some(2));
+    }
+
+    #[test]
+    fn parse_rejects_turns_too_large_for_i16() {
+        assert_eq!(parse("R32767"), Ok(32767));
+        let error = parse("R40000").unwrap_err();
+        assert_eq!(error.column, Some(2));
+        assert!(error.cause.contains("out of range"), "{}", error);
+    }
+
+    #[test]
+    fn parse_reports_empty_lines() {
+        let error = Day01.parse("L5\n\nR3\n").unwrap_err();
+        assert_eq!(error.to_string(), "line 2: Empty line in input");
     }
 
     #[test]
     fn solve_examples() {
-        assert_eq!(solve(&[1, -1], &0, &100), 1); // example: sum
-        assert_eq!(solve(&[-18], &0, &100), 0); // single element slice
-        assert_eq!(solve(&[50, 50], &0, &100), 1); // 50 → 100 mod 100 = 0
-        assert_eq!(solve(&[50, 150], &0, &100), 1); // 50 → 100 mod 100 = 0
+        assert_eq!(solve(&[1, -1], &0, &100), Ok(1)); // example: sum
+        assert_eq!(solve(&[-18], &0, &100), Ok(0)); // single element slice
+        assert_eq!(solve(&[50, 50], &0, &100), Ok(1)); // 50 → 100 mod 100 = 0
+        assert_eq!(solve(&[50, 150], &0, &100), Ok(1)); // 50 → 100 mod 100 = 0
+        assert!(solve(&[1], &100, &100).is_err()); // start must be on the dial
     }
 
 #[test]
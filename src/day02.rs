@@ -1,28 +1,54 @@
-use std::fs;
-
-pub fn run() {
-    println!("Running Day 02 challenge...");
-    // Add your Day 02 challenge code here
-    let input = fs::read_to_string("inputs/input_day02.txt")
-        .expect("Failed to read input file");
-    let extracted_ranges: Vec<(u64,u64)> = input.split(',')
-        .map(|term| parse(term).expect("Failed to parse term"))
-        .collect();
-    println!("Solution Part 1: {}", solve(&extracted_ranges));
-    println!("Solution Part 2: {}", solve_part2(&extracted_ranges));
-}  
-
-fn parse(term: &str) -> Result<(u64,u64), String> {
-    // Ensure the line is not empty
-    assert!(!term.is_empty(), "Empty line in input");
-    let parts: Vec<&str> = term.trim().split('-').collect();
-    
-    assert!(parts.len() == 2, "Expected two parts in term: {}", term);
-   
-    let left = parts[0].parse::<u64>()
-        .map_err(|_| format!("Failed to parse left part '{}' as u64", parts[0]))?;
-    let right = parts[1].parse::<u64>()
-        .map_err(|_| format!("Failed to parse right part '{}' as u64", parts[1]))?;
+use crate::error::{AocError, AocResult};
+use crate::solution::{Answer, Solution};
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+    type Input = Vec<(u64, u64)>;
+
+    fn parse(&self, input: &str) -> AocResult<Self::Input> {
+        let mut start = 0;
+        input.split(',')
+            .map(|term| {
+                // Columns from `parse` are relative to the trimmed term
+                let term_start = start + term.len() - term.trim_start().len();
+                start += term.len() + 1;
+                parse(term).map_err(|e| e.within(input, term_start))
+            })
+            .collect()
+    }
+
+    fn part1(&self, extracted_ranges: &Self::Input) -> AocResult<Answer> {
+        Ok(solve(extracted_ranges).into())
+    }
+
+    fn part2(&self, extracted_ranges: &Self::Input) -> AocResult<Answer> {
+        Ok(solve_part2(extracted_ranges).into())
+    }
+}
+
+pub fn parse(term: &str) -> AocResult<(u64,u64)> {
+    // Ensure the term is not empty
+    let term = term.trim();
+    if term.is_empty() {
+        return Err(AocError::new("Empty term in input"));
+    }
+    let (left_str, right_str) = term.split_once('-')
+        .ok_or_else(|| AocError::new(format!("Expected two parts in term: {}", term)).at_column(1))?;
+
+    // Point at the first character that isn't a digit, if any
+    let bad = |s: &str| s.find(|c: char| !c.is_ascii_digit()).unwrap_or(0);
+    let left = left_str.parse::<u64>()
+        .map_err(|_| {
+            AocError::new(format!("Failed to parse left part {:?} as u64", left_str))
+                .at_column(1 + bad(left_str))
+        })?;
+    let right = right_str.parse::<u64>()
+        .map_err(|_| {
+            AocError::new(format!("Failed to parse right part {:?} as u64", right_str))
+                .at_column(left_str.len() + 2 + bad(right_str))
+        })?;
 
     Ok((left, right))
 }
@@ -43,9 +69,9 @@ fn get_num_len_log(n: u64) -> u32 {
     (n as f64).log10().floor() as u32 + 1
 }
 
-fn solve(input: &[(u64,u64)]) ->  u64 {
+pub fn solve(input: &[(u64,u64)]) ->  u64 {
     // Step one, map over each range
-    return input.iter().map(|(start, end)| {
+    input.iter().map(|(start, end)| {
         let mut id_cumsum: u64 = 0;
 
         for num in *start..=*end {
@@ -56,7 +82,7 @@ fn solve(input: &[(u64,u64)]) ->  u64 {
             let d_len = get_num_len_log(num);
             
             // 1. Check if length is even (L = 2K)
-            if d_len % 2 == 0 { 
+            if d_len.is_multiple_of(2) { 
                 
                 let pat_len = d_len / 2; // Pattern length K
                 
@@ -71,14 +97,14 @@ fn solve(input: &[(u64,u64)]) ->  u64 {
             }
         }
         id_cumsum
-    }).sum();
+    }).sum()
 }
 
-fn solve_part2(input: &[(u64,u64)]) -> u64 {
+pub fn solve_part2(input: &[(u64,u64)]) -> u64 {
     // Powers of 10 are now accessed via the global const POWERS_OF_10
     // No local array initialization needed!
 
-    return input.iter().map(|(start, end)| {
+    input.iter().map(|(start, end)| {
         let mut id_cumsum: u64 = 0;
 
         'num_loop: for num in *start..=*end {
@@ -90,7 +116,7 @@ fn solve_part2(input: &[(u64,u64)]) -> u64 {
             
             // Iterate over all possible number of repetitions R >= 2
             for pat_len in 1..=(d_len / 2) {
-                if d_len % pat_len == 0 {
+                if d_len.is_multiple_of(pat_len) {
                     
                     // Divisor D = (10^L - 1) / (10^K - 1)
                     let numerator = POWERS_OF_10_M1[d_len as usize]; 
@@ -107,7 +133,7 @@ fn solve_part2(input: &[(u64,u64)]) -> u64 {
             }
         }
         id_cumsum
-    }).sum();
+    }).sum()
 }
 
 #[cfg(test)]
@@ -124,6 +150,17 @@ mod tests {
         assert_eq!(parse("140500-1839201").unwrap(), (140500, 1839201));
     }
 
+    #[test]
+    fn parse_reports_position_of_bad_term() {
+        assert_eq!(parse("10-1x").unwrap_err().column, Some(5));
+        assert_eq!(parse("1 0-12").unwrap_err().column, Some(2));
+        assert!(parse("10").is_err());
+        assert!(parse("1-2-3").is_err());
+
+        let error = Day02.parse("11-22, 95-x\n").unwrap_err();
+        assert_eq!((error.line, error.column), (Some(1), Some(11)));
+    }
+
     // --- Part 1 Tests (Must have EVEN length, R=2) ---
     #[test]
     fn solve_part1_no_match() {
@@ -1,17 +1,44 @@
-use std::fs;
+use crate::error::{AocError, AocResult};
+use crate::solution::{Answer, Solution};
 
-pub fn run() {
-    println!("Running Day 03 challenge...");
-    // Add your Day 02 challenge code here
-    let input = fs::read_to_string("inputs/input_day03.txt")
-        .expect("Failed to read input file");
-    let batteries: Vec<&str> = input.lines().collect();
-    println!("Solution Part 1: {}", solve(&batteries));
-    println!("Solution Part 2: {}", solve_part2(&batteries));
-}  
+pub struct Day03;
 
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+    type Input = Vec<Vec<u8>>;
 
-fn solve(input: &[&str]) ->  u64 {
+    /// One row of digits per bank of batteries.
+    fn parse(&self, input: &str) -> AocResult<Self::Input> {
+        input
+            .lines()
+            .enumerate()
+            .map(|(row, line)| {
+                line.chars()
+                    .enumerate()
+                    .map(|(i, c)| c.to_digit(10).map(|d| d as u8).ok_or_else(|| not_a_digit(row, i, c)))
+                    .collect()
+            })
+            .collect()
+    }
+
+    fn part1(&self, batteries: &Self::Input) -> AocResult<Answer> {
+        Ok(solve(batteries).into())
+    }
+
+    fn part2(&self, batteries: &Self::Input) -> AocResult<Answer> {
+        Ok(solve_part2(batteries).into())
+    }
+}
+
+// Lines and columns are 0-based indices into the input
+fn not_a_digit(line: usize, column: usize, c: char) -> AocError {
+    AocError::new(format!("Char was not a digit: {}", c))
+        .on_line(line + 1)
+        .at_column(column + 1)
+}
+
+
+pub fn solve(input: &[Vec<u8>]) -> u64 {
     // Step one, map over each range
     let mut joltage: u64 = 0;
     let mut big1:  u8;
@@ -23,8 +50,7 @@ fn solve(input: &[&str]) ->  u64 {
         idx1 = 0;
         big2 = 0;
         idx2 = 0;
-        for (i, v) in battery.chars().enumerate() {
-            let v: u8 = v.to_digit(10).expect(&format!("Char was not a digit {}", battery)) as u8;
+        for (i, &v) in battery.iter().enumerate() {
             if big1 == big2 && big1 == 9 {
                   break;
             }
@@ -41,18 +67,17 @@ fn solve(input: &[&str]) ->  u64 {
         joltage += if idx1 < idx2 {(big1 as u64) * 10 + (big2 as u64)} else {(big2 as u64) * 10 + (big1 as u64)};
     }
 
-    return joltage;
+    joltage
 }
 
-fn solve_part2(input: &[&str]) ->  u64 {
+pub fn solve_part2(input: &[Vec<u8>]) -> u64 {
     // Step one, map over each range
     let mut joltage: u64 = 0;
     for battery in input {
         let to_pick = 12;
         let mut stack: Vec<u8> = Vec::new();
         let n = battery.len();
-        for (i, v) in battery.chars().enumerate() {
-            let v: u8 = v.to_digit(10).expect(&format!("Char was not a digit {}", battery)) as u8;
+        for (i, &v) in battery.iter().enumerate() {
             let remaining = n - i;
             while let Some(&last) = stack.last() {
                 if last < v && remaining + stack.len() > to_pick {
@@ -70,7 +95,7 @@ fn solve_part2(input: &[&str]) ->  u64 {
         joltage += stack.iter().fold(0, |acc, &d| acc * 10 + d as u64); 
     }
 
-    return joltage;
+    joltage
 }
 
 
@@ -79,21 +104,30 @@ fn solve_part2(input: &[&str]) ->  u64 {
 mod tests {
     use super::*;
 
+    fn digits(rows: &[&str]) -> Vec<Vec<u8>> {
+        Day03.parse(&rows.join("\n")).unwrap()
+    }
+
     #[test]
     fn solve_examples() {
-        assert_eq!(solve(&["12788"]), 88); 
-        assert_eq!(solve(&["1278"]), 78); 
-        assert_eq!(solve(&["9988"]), 99); 
-        assert_eq!(solve(&["88989"]), 99); 
-        assert_eq!(solve(&["12000"]), 20); 
-        assert_eq!(solve(&["12000", "1278"]), 98); 
-        assert_eq!(solve(&["12892000"]), 92); 
-        assert_eq!(solve(&["987654321111111", "811111111111119", "234234234234278", "818181911112111"]), 357); 
+        assert_eq!(solve(&digits(&["12788"])), 88); 
+        assert_eq!(solve(&digits(&["1278"])), 78); 
+        assert_eq!(solve(&digits(&["9988"])), 99); 
+        assert_eq!(solve(&digits(&["88989"])), 99); 
+        assert_eq!(solve(&digits(&["12000"])), 20); 
+        assert_eq!(solve(&digits(&["12000", "1278"])), 98); 
+        assert_eq!(solve(&digits(&["12892000"])), 92); 
+        assert_eq!(solve(&digits(&["987654321111111", "811111111111119", "234234234234278", "818181911112111"])), 357); 
     }
     #[test]
     fn solve_examples_part2() {
-        assert_eq!(solve_part2(&["987654321111111", "811111111111119", "234234234234278", "818181911112111"]), 3121910778619); 
+        assert_eq!(solve_part2(&digits(&["987654321111111", "811111111111119", "234234234234278", "818181911112111"])), 3121910778619); 
+    }
+    #[test]
+    fn non_digits_are_reported_with_their_position() {
+        let error = Day03.parse("123\n1x3\n").unwrap_err();
+        assert_eq!((error.line, error.column), (Some(2), Some(2)));
+        assert!(Day03.parse("12a").is_err());
     }
 
 }
-
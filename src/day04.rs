@@ -1,21 +1,32 @@
-use std::fs;
-
-pub fn run() {
-    println!("Running Day 04 challenge...");
-    let input = fs::read_to_string("inputs/input_day04.txt")
-        .expect("Failed to read input file");
-    let (toilet_rolls, rows, cols): (Vec<bool>, usize, usize) = parse(&input).expect("Failed to parse");
-   
-    println!("Solution Part 1: {}", solve(&toilet_rolls, rows, cols));
-    println!("Solution Part 2: {}", solve_part2(&toilet_rolls, rows, cols));
-}  
-
-fn parse(input: &str) -> Result<(Vec<bool>, usize, usize), String> {
+use crate::error::{AocError, AocResult};
+use crate::solution::{Answer, Solution};
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+    type Input = (Vec<bool>, usize, usize);
+
+    fn parse(&self, input: &str) -> AocResult<Self::Input> {
+        parse(input)
+    }
+
+    fn part1(&self, (toilet_rolls, rows, cols): &Self::Input) -> AocResult<Answer> {
+        Ok(solve(toilet_rolls, *rows, *cols).into())
+    }
+
+    fn part2(&self, (toilet_rolls, rows, cols): &Self::Input) -> AocResult<Answer> {
+        Ok(solve_part2(toilet_rolls, *rows, *cols).into())
+    }
+}
+
+pub fn parse(input: &str) -> AocResult<(Vec<bool>, usize, usize)> {
     let mut data = Vec::new();
     let mut rows = 0;
     let mut cols = 0;
     
-    for line in input.lines().filter(|l| !l.is_empty()) {
+    // Enumerate before skipping blank lines so errors point at the right line
+    for (line_number, line) in input.lines().enumerate().filter(|(_, l)| !l.is_empty()) {
         rows += 1;
         let line_len = line.len();
 
@@ -23,14 +34,19 @@ fn parse(input: &str) -> Result<(Vec<bool>, usize, usize), String> {
         if cols == 0 {
             cols = line_len;
         } else if line_len != cols {
-            return Err(format!("Row {} has length {} but expected {}", rows, line_len, cols));
+            return Err(AocError::new(format!("Row {} has length {} but expected {}", rows, line_len, cols))
+                .on_line(line_number + 1));
         }
 
-        for c in line.chars() {
+        for (column, c) in line.chars().enumerate() {
             match c {
                 '@' => data.push(true),
                 '.' => data.push(false),
-                _ => return Err(format!("Invalid character '{}' in line: {}", c, line)),
+                _ => {
+                    return Err(AocError::new(format!("Invalid character {:?}", c))
+                        .on_line(line_number + 1)
+                        .at_column(column + 1))
+                }
             }
         }
     }
@@ -38,7 +54,7 @@ fn parse(input: &str) -> Result<(Vec<bool>, usize, usize), String> {
     Ok((data, rows, cols))
 }
 
-fn solve(input: &[bool], rows: usize, cols: usize) ->  u64 {
+pub fn solve(input: &[bool], rows: usize, cols: usize) ->  u64 {
     // Step one make a matrix which runs a 2d convolution to count neighbours.
     // The convolution kernel is:[1,1,1],[1,0,1],[1,1,1]
     // Note that we want the matrix to be returned as the same size, so we assume oob values are 
@@ -90,10 +106,10 @@ fn solve(input: &[bool], rows: usize, cols: usize) ->  u64 {
         }
     }   
 
-    return total_count;
+    total_count
 }
 
-fn solve_part2(input: &[bool], rows: usize, cols: usize) -> u64 {
+pub fn solve_part2(input: &[bool], rows: usize, cols: usize) -> u64 {
     
     let mut total_removed = 0;
     let mut current_matrix  = input.to_vec();
@@ -113,7 +129,7 @@ fn solve_part2(input: &[bool], rows: usize, cols: usize) -> u64 {
 }
 
 
-fn one_step_update(input: &[bool], return_matrix: &mut [bool], rows: usize, cols: usize) -> u64 {
+pub fn one_step_update(input: &[bool], return_matrix: &mut [bool], rows: usize, cols: usize) -> u64 {
 
     let mut convolved_matrix: Vec<u8> = vec![0; rows * cols];
 
@@ -165,7 +181,7 @@ fn one_step_update(input: &[bool], return_matrix: &mut [bool], rows: usize, cols
         }
     }
     
-    return count_removed;
+    count_removed
 }
 
 #[cfg(test)]
@@ -195,8 +211,6 @@ mod tests {
         // 2. Call solve with the correct arguments
         let result = solve(&toilet_rolls, rows, cols);
         
-        // The expected result for Part 1 based on similar problems (e.g., Conway's Game of Life rules)
-        // Note: The expected value 13 is assumed to be correct based on the original broken test.
         assert_eq!(result, 13);
     }
 
@@ -209,7 +223,6 @@ mod tests {
         // 2. Call solve_part2 with the correct arguments
         let result = solve_part2(&toilet_rolls, rows, cols);
         
-        // Note: The expected value 43 is assumed to be correct based on the original broken test.
         assert_eq!(result, 43);
     }
 
@@ -223,4 +236,14 @@ mod tests {
         assert_eq!(cols, 10);
         assert_eq!(toilet_rolls.len(), 10 * 10);
     }
+
+    #[test]
+    fn parse_reports_position_of_bad_input() {
+        // Blank lines are skipped but still counted
+        let error = parse("\n..@\n.x@\n").unwrap_err();
+        assert_eq!((error.line, error.column), (Some(3), Some(2)));
+
+        let error = parse("..@\n.@\n").unwrap_err();
+        assert_eq!(error.to_string(), "line 2: Row 2 has length 2 but expected 3");
+    }
 }
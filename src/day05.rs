@@ -1,73 +1,109 @@
-use std::fs;
+use crate::error::{AocError, AocResult};
+use crate::solution::{Answer, Solution};
 
-pub fn run() {
-    println!("Running Day 05 challenge...");
-    
-    // Read the file content
-    let input = fs::read_to_string("inputs/input_day05.txt")
-        .expect("Failed to read input file: inputs/input_day05.txt");
-    
-    // Split the input into two parts based on the blank line separator
-    let parts: Vec<&str> = input.split("\n\n").collect();
-    
-    // Check for exactly two parts
-    assert!(
-        parts.len() == 2, 
-        "Error: Input file should contain exactly two parts separated by a blank line (\\n\\n). Found {} parts.", parts.len()
-    );
-
-    // Parse the range lines from the first part
-    let extracted_ranges: Vec<(u64,u64)> = parts[0]
-        .lines()
-        .filter(|line| !line.trim().is_empty()) // Ignore empty lines
-        .map(|term| parse_ranges(term).expect("Failed to parse range term"))
-        .collect();
-
-    // Parse the individual values from the second part
-    let values: Vec<u64> = parts[1]
-        .lines()
-        .filter(|line| !line.trim().is_empty()) // Ignore empty lines
-        .map(|line| {
-            line.trim()
-                .parse::<u64>()
-                .expect(&format!("Failed to parse line '{}' as u64", line))
-        })
-        .collect();
-
-    // OPTIMIZATION: Move ownership of extracted_ranges to consolidate_ranges 
-    // to avoid an unnecessary clone.
-    let consolidated_ranges: Vec<(u64, u64)> = consolidate_ranges(extracted_ranges);
-    
-    // Solve
-    println!("Solution Part 1: {}", solve_part1(&consolidated_ranges, &values));
-    println!("Solution Part 2: {}", solve_part2(&consolidated_ranges));
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+    // Consolidated ranges and the values to look up in them
+    type Input = (Vec<(u64, u64)>, Vec<u64>);
+
+    fn parse(&self, input: &str) -> AocResult<Self::Input> {
+        // Split the input into two parts based on the blank line separator
+        let parts: Vec<&str> = input.split("\n\n").collect();
+
+        // Check for exactly two parts
+        if parts.len() != 2 {
+            return Err(AocError::new(format!(
+                "Input file should contain exactly two parts separated by a blank line (\\n\\n). Found {} parts.",
+                parts.len()
+            )));
+        }
+
+        // Parse the range lines from the first part
+        let extracted_ranges: Vec<(u64,u64)> = parts[0]
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty()) // Ignore empty lines
+            .map(|(i, term)| parse_ranges(term).map_err(|e| e.on_line(i + 1)))
+            .collect::<AocResult<_>>()?;
+
+        // Parse the individual values from the second part, which starts
+        // after the blank line
+        let values_start = parts[0].len() + 2;
+        let values: Vec<u64> = parts[1]
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty()) // Ignore empty lines
+            .map(|(i, line)| {
+                line.trim().parse::<u64>().map_err(|_| {
+                    let value = line.trim_start();
+                    let bad = value.find(|c: char| !c.is_ascii_digit()).unwrap_or(0);
+                    AocError::new(format!("Failed to parse line {:?} as u64", line))
+                        .on_line(i + 1)
+                        .at_column(line.len() - value.len() + bad + 1)
+                        .within(input, values_start)
+                })
+            })
+            .collect::<AocResult<_>>()?;
+
+        // OPTIMIZATION: Move ownership of extracted_ranges to consolidate_ranges 
+        // to avoid an unnecessary clone.
+        Ok((consolidate_ranges(extracted_ranges), values))
+    }
+
+    fn part1(&self, (consolidated_ranges, values): &Self::Input) -> AocResult<Answer> {
+        Ok(solve_part1(consolidated_ranges, values).into())
+    }
+
+    fn part2(&self, (consolidated_ranges, _): &Self::Input) -> AocResult<Answer> {
+        Ok(solve_part2(consolidated_ranges).into())
+    }
 }
 
 // Function to parse a single range string (e.g., "10-20")
-fn parse_ranges(term: &str) -> Result<(u64,u64), String> {
+pub fn parse_ranges(term: &str) -> AocResult<(u64,u64)> {
     let trimmed_term = term.trim();
     if trimmed_term.is_empty() {
-        return Err("Empty line encountered during range parsing".to_string());
+        return Err(AocError::new("Empty line encountered during range parsing"));
     }
     
     // Use split_once for robust parsing of exactly one separator
     let (left_str, right_str) = trimmed_term.split_once('-')
-        .ok_or_else(|| format!("Expected exactly one '-' in term: {}", term))?;
+        .ok_or_else(|| AocError::new(format!("Expected exactly one '-' in term: {}", term)))?;
     
+    // Columns of the first bad character of each boundary in the untrimmed
+    // term
+    let leading = |s: &str| s.len() - s.trim_start().len();
+    let bad = |s: &str| s.trim().find(|c: char| !c.is_ascii_digit()).unwrap_or(0);
+    let left_column = leading(term) + leading(left_str) + bad(left_str) + 1;
+    let right_column = leading(term) + left_str.len() + 1 + leading(right_str) + bad(right_str) + 1;
+
     // Parse left boundary
     let left = left_str.trim().parse::<u64>()
-        .map_err(|e| format!("Failed to parse left part '{}' as u64: {}", left_str.trim(), e))?;
+        .map_err(|e| {
+            AocError::new(format!("Failed to parse left part '{}' as u64: {}", left_str.trim(), e))
+                .at_column(left_column)
+        })?;
     
     // Parse right boundary
     let right = right_str.trim().parse::<u64>()
-        .map_err(|e| format!("Failed to parse right part '{}' as u64: {}", right_str.trim(), e))?;
+        .map_err(|e| {
+            AocError::new(format!("Failed to parse right part '{}' as u64: {}", right_str.trim(), e))
+                .at_column(right_column)
+        })?;
+
+    // A reversed range would underflow when its length is taken
+    if left > right {
+        return Err(AocError::new(format!("Range {}-{} ends before it starts", left, right)).at_column(left_column));
+    }
 
     Ok((left, right))
 }
 
 // Pre-processes the overlapping, unsorted ranges into a minimal list
 // of non-overlapping, sorted ranges.
-fn consolidate_ranges(mut ranges: Vec<(u64, u64)>) -> Vec<(u64, u64)> {
+pub fn consolidate_ranges(mut ranges: Vec<(u64, u64)>) -> Vec<(u64, u64)> {
     if ranges.is_empty() {
         return Vec::new();
     }
@@ -80,8 +116,7 @@ fn consolidate_ranges(mut ranges: Vec<(u64, u64)>) -> Vec<(u64, u64)> {
     let mut current_end = ranges[0].1;
 
     // B. Merge overlapping ranges. O(R)
-    for i in 1..ranges.len() {
-        let (next_start, next_end) = ranges[i];
+    for &(next_start, next_end) in &ranges[1..] {
 
         // Check if the next range overlaps or touches the current consolidated range
         if next_start <= current_end + 1 { // +1 for touching (e.g., [1,4] and [5,10] merge to [1,10])
@@ -102,7 +137,7 @@ fn consolidate_ranges(mut ranges: Vec<(u64, u64)>) -> Vec<(u64, u64)> {
 
 // Checks if n is in any of the consolidated, non-overlapping ranges using binary search.
 // Time complexity: O(log R_new) per query.
-fn is_in_consolidated_range(n: &u64, consolidated_ranges: &[(u64, u64)]) -> bool {
+pub fn is_in_consolidated_range(n: &u64, consolidated_ranges: &[(u64, u64)]) -> bool {
     // Binary Search: Find the insertion point (index 'i' where n would go), based on start points.
     let index = match consolidated_ranges.binary_search_by_key(n, |r| r.0) {
         
@@ -128,7 +163,7 @@ fn is_in_consolidated_range(n: &u64, consolidated_ranges: &[(u64, u64)]) -> bool
 
 // Part 1: Count how many values fall within the consolidated ranges.
 // Uses an iterator chain for idiomatic counting.
-fn solve_part1(consolidated_ranges: &[(u64,u64)], values: &[u64]) -> u64 {
+pub fn solve_part1(consolidated_ranges: &[(u64,u64)], values: &[u64]) -> u64 {
     values.iter()
         .filter(|value| is_in_consolidated_range(value, consolidated_ranges))
         .count() as u64
@@ -136,7 +171,7 @@ fn solve_part1(consolidated_ranges: &[(u64,u64)], values: &[u64]) -> u64 {
 
 // Part 2: Calculate the total length of all consolidated ranges.
 // Rewritten as an iterator chain using map and sum.
-fn solve_part2(consolidated_ranges: &[(u64,u64)])-> u64 {
+pub fn solve_part2(consolidated_ranges: &[(u64,u64)])-> u64 {
     consolidated_ranges.iter()
         // Map each range (start, end) to its length: end - start + 1
         .map(|&(start, end)| end - start + 1)
@@ -154,15 +189,21 @@ mod tests {
             (10, 20),
             (5, 12),
             (25, 30),
-            (21, 24), // Touches 25-30
+            (21, 24), // Touches 25-30 (and 10-20)
             (1, 4), // Touches 5-12
         ];
-        // Expected consolidated ranges: (1, 20), (21, 30)
-        let expected_output: Vec<(u64, u64)> = vec![(1, 20), (21, 30)];
+        // Touching ranges are merged, so everything collapses to (1, 30)
+        let expected_output: Vec<(u64, u64)> = vec![(1, 30)];
 
         assert_eq!(consolidate_ranges(ranges_input), expected_output);
     }
 
+    #[test]
+    fn test_consolidate_ranges_merges_only_touching_ranges() {
+        assert_eq!(consolidate_ranges(vec![(5, 10), (1, 4)]), vec![(1, 10)]);
+        assert_eq!(consolidate_ranges(vec![(6, 10), (1, 4)]), vec![(1, 4), (6, 10)]);
+    }
+
     #[test]
     fn test_solve_part1_range_containment() {
         // Consolidated ranges from input: (3, 5), (10, 18), (19, 20) -> NOTE: consolidation should happen first!
@@ -192,6 +233,23 @@ mod tests {
     }
 
 
+    #[test]
+    fn test_parse_reports_bad_lines() {
+        let error = Day05.parse("3-5\n10-x\n\n1\n").unwrap_err();
+        assert_eq!((error.line, error.column), (Some(2), Some(4)));
+
+        let error = Day05.parse("3-5\n10-1 4\n\n1\n").unwrap_err();
+        assert_eq!((error.line, error.column), (Some(2), Some(5)));
+
+        let error = Day05.parse("3-5\n10-14\n\n1\nfive\n").unwrap_err();
+        assert_eq!(error.to_string(), "line 5, column 1: Failed to parse line \"five\" as u64");
+
+        let error = Day05.parse("3-5\n 10-5\n\n7\n").unwrap_err();
+        assert_eq!(error.to_string(), "line 2, column 2: Range 10-5 ends before it starts");
+
+        assert!(Day05.parse("3-5\n").is_err());
+    }
+
     #[test]
     fn test_solve_part2() {
         // Ranges: (3, 5), (10, 14), (16, 20), (12, 18)
@@ -0,0 +1,225 @@
+use std::fmt;
+
+use colored::Colorize;
+
+/// Why a day could not parse its input or solve a part.
+///
+/// Line and column are 1-based and point into the puzzle input. Parsers
+/// usually know only part of the position: a line parser knows the column,
+/// the loop calling it knows the line, and the runner knows the day, so
+/// each fills in what it can as the error travels up.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AocError {
+    pub day: Option<u8>,
+    pub line: Option<usize>,
+    pub column: Option<usize>,
+    pub cause: String,
+    /// Text of the offending input line, for [`AocError::diagnostic`].
+    pub source_line: Option<String>,
+}
+
+pub type AocResult<T> = Result<T, AocError>;
+
+impl AocError {
+    pub fn new(cause: impl Into<String>) -> AocError {
+        AocError {
+            day: None,
+            line: None,
+            column: None,
+            cause: cause.into(),
+            source_line: None,
+        }
+    }
+
+    pub fn for_day(mut self, day: u8) -> AocError {
+        self.day = Some(day);
+        self
+    }
+
+    pub fn on_line(mut self, line: usize) -> AocError {
+        self.line = Some(line);
+        self
+    }
+
+    pub fn at_column(mut self, column: usize) -> AocError {
+        self.column = Some(column);
+        self
+    }
+
+    /// Move an error found in `&input[start..]` to its position in `input`.
+    /// An error with no line is taken to be on the slice's first line.
+    pub fn within(mut self, input: &str, start: usize) -> AocError {
+        let before = &input[..start];
+        let line = self.line.unwrap_or(1);
+        if line == 1 {
+            let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+            let shift = before[line_start..].chars().count();
+            self.column = self.column.map(|column| column + shift);
+        }
+        self.line = Some(before.matches('\n').count() + line);
+        self
+    }
+
+    /// Keep a copy of the line the error points at in `input`. Unlike
+    /// `str::lines`, a trailing `\r` is kept so it can be shown.
+    pub fn with_source(mut self, input: &str) -> AocError {
+        if let Some(line) = self.line {
+            self.source_line = input.split('\n').nth(line - 1).map(str::to_string);
+        }
+        self
+    }
+
+    /// The offending line of `origin` with a caret under the bad column,
+    /// compiler style. `None` unless the line is known.
+    pub fn diagnostic(&self, origin: &str) -> Option<String> {
+        let line = self.line?;
+        let source = self.source_line.as_deref()?;
+        let gutter = " ".repeat(line.to_string().len());
+
+        let mut out = format!("{}: {}\n", "error".red().bold(), self.cause);
+        match self.column {
+            Some(column) => out.push_str(&format!("{}--> {}:{}:{}\n", gutter, origin, line, column)),
+            None => out.push_str(&format!("{}--> {}:{}\n", gutter, origin, line)),
+        }
+        out.push_str(&format!("{} |\n", gutter));
+        let (shown, caret_at, caret_width) = excerpt(source, self.column);
+        out.push_str(&format!("{} | {}", line, shown));
+        if self.column.is_some() {
+            let caret = "^".repeat(caret_width);
+            out.push_str(&format!("\n{} | {}{}", gutter, " ".repeat(caret_at), caret.red().bold()));
+        }
+        Some(out)
+    }
+}
+
+/// How a character is shown in a diagnostic. Control characters such as a
+/// stray `\r` are escaped so they can be seen.
+fn escape(c: char) -> String {
+    if c.is_control() {
+        c.escape_default().to_string()
+    } else {
+        c.to_string()
+    }
+}
+
+/// Longest stretch of a line shown before and after the bad column.
+const EXCERPT_BEFORE: usize = 60;
+const EXCERPT_AFTER: usize = 20;
+
+/// `source` as shown in a diagnostic, cut down around `column` if it is
+/// long, with where the caret starts and how wide it is.
+fn excerpt(source: &str, column: Option<usize>) -> (String, usize, usize) {
+    let chars: Vec<char> = source.chars().collect();
+    let at = column.map_or(0, |c| c.saturating_sub(1)).min(chars.len());
+    let (start, end) = if chars.len() > EXCERPT_BEFORE + EXCERPT_AFTER {
+        let start = at.saturating_sub(EXCERPT_BEFORE);
+        (start, (at + EXCERPT_AFTER).min(chars.len()))
+    } else {
+        (0, chars.len())
+    };
+
+    let mut shown = String::new();
+    if start > 0 {
+        shown.push_str("...");
+    }
+    shown.extend(chars[start..at].iter().map(|&c| escape(c)));
+    let caret_at = shown.chars().count();
+    // A column just past the end points at what is missing there
+    let caret_width = chars.get(at).map_or(1, |&c| escape(c).chars().count());
+    shown.extend(chars[at..end].iter().map(|&c| escape(c)));
+    if end < chars.len() {
+        shown.push_str("...");
+    }
+    (shown, caret_at, caret_width)
+}
+
+impl fmt::Display for AocError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut position = Vec::new();
+        if let Some(day) = self.day {
+            position.push(format!("day {}", day));
+        }
+        if let Some(line) = self.line {
+            position.push(format!("line {}", line));
+        }
+        if let Some(column) = self.column {
+            position.push(format!("column {}", column));
+        }
+        if position.is_empty() {
+            write!(f, "{}", self.cause)
+        } else {
+            write!(f, "{}: {}", position.join(", "), self.cause)
+        }
+    }
+}
+
+impl std::error::Error for AocError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn display_includes_what_is_known() {
+        let error = AocError::new("Invalid direction 'X'");
+        assert_eq!(error.to_string(), "Invalid direction 'X'");
+        let error = error.at_column(1).on_line(3).for_day(1);
+        assert_eq!(error.to_string(), "day 1, line 3, column 1: Invalid direction 'X'");
+        let error = AocError::new("Expected two sections").for_day(5);
+        assert_eq!(error.to_string(), "day 5: Expected two sections");
+        let error = AocError::new("Empty line").on_line(2);
+        assert_eq!(error.to_string(), "line 2: Empty line");
+    }
+
+    #[test]
+    fn diagnostic_points_at_the_column() {
+        colored::control::set_override(false);
+        let error = AocError::new("Invalid character 'x'")
+            .on_line(12)
+            .at_column(3)
+            .with_source(&format!("{}.@x\n", "..@\n".repeat(11)));
+        let expected = "\
+error: Invalid character 'x'
+  --> inputs/input_day04.txt:12:3
+   |
+12 | .@x
+   |   ^";
+        assert_eq!(error.diagnostic("inputs/input_day04.txt").unwrap(), expected);
+    }
+
+    #[test]
+    fn diagnostic_shows_invisible_characters() {
+        colored::control::set_override(false);
+        let error = AocError::new("Invalid character '\\r'")
+            .on_line(1)
+            .at_column(4)
+            .with_source("..@\r\n");
+        let diagnostic = error.diagnostic("-").unwrap();
+        assert!(diagnostic.ends_with("1 | ..@\\r\n  |    ^^"), "{}", diagnostic);
+    }
+
+    #[test]
+    fn diagnostic_trims_long_lines() {
+        let source = format!("{}x{}", "1".repeat(200), "2".repeat(200));
+        let (shown, caret_at, width) = excerpt(&source, Some(201));
+        assert_eq!(shown, format!("...{}x{}...", "1".repeat(60), "2".repeat(19)));
+        assert_eq!((caret_at, width), (63, 1));
+    }
+
+    #[test]
+    fn diagnostic_needs_the_source_line() {
+        assert_eq!(AocError::new("Bad").on_line(1).diagnostic("-"), None);
+        assert_eq!(AocError::new("Bad").with_source("a\n").diagnostic("-"), None);
+    }
+
+    #[test]
+    fn within_rebases_onto_the_whole_input() {
+        let input = "1-2,3-x\n\n4\ny\n";
+        // Column 3 of the term starting at byte 4
+        let error = AocError::new("bad").at_column(3).within(input, 4);
+        assert_eq!((error.line, error.column), (Some(1), Some(7)));
+        // Line 2 of the section starting after the blank line
+        let error = AocError::new("bad").on_line(2).at_column(1).within(input, 10);
+        assert_eq!((error.line, error.column), (Some(4), Some(1)));
+    }
+}
@@ -0,0 +1,141 @@
+use std::any::Any;
+use std::cell::{Cell, RefCell};
+use std::fmt;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::Once;
+use std::time::Duration;
+
+use crate::error::{AocError, AocResult};
+
+/// Why a stage of a day produced no answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Failure {
+    /// The stage (`"parse"`, `"part 1"` or `"part 2"`) panicked.
+    /// `location` is `file:line:col` when known.
+    Panic {
+        stage: &'static str,
+        message: String,
+        location: Option<String>,
+    },
+    /// The stage was still running after `limit` and was abandoned.
+    Timeout {
+        stage: &'static str,
+        limit: Duration,
+    },
+    /// The stage returned an error, usually because of malformed input.
+    Error {
+        stage: &'static str,
+        error: AocError,
+    },
+}
+
+impl fmt::Display for Failure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Failure::Panic {
+                stage,
+                message,
+                location: Some(location),
+            } => write!(f, "{} panicked at {}: {}", stage, location, message),
+            Failure::Panic {
+                stage,
+                message,
+                location: None,
+            } => write!(f, "{} panicked: {}", stage, message),
+            Failure::Timeout { stage, limit } => write!(f, "{} timed out after {:?}", stage, limit),
+            Failure::Error { stage, error } => write!(f, "{} failed: {}", stage, error),
+        }
+    }
+}
+
+thread_local! {
+    /// Set while `catch` is running on this thread.
+    static CATCHING: Cell<bool> = const { Cell::new(false) };
+    /// Message and location of the last panic caught on this thread.
+    static LAST_PANIC: RefCell<Option<(String, Option<String>)>> = const { RefCell::new(None) };
+}
+
+/// Install a panic hook that records panics raised inside `catch` instead
+/// of printing them. Panics anywhere else still go to the previous hook.
+fn install_panic_hook() {
+    static INSTALL: Once = Once::new();
+    INSTALL.call_once(|| {
+        let previous = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if CATCHING.get() {
+                let location = info.location().map(|l| l.to_string());
+                LAST_PANIC.set(Some((payload_message(info.payload()), location)));
+            } else {
+                previous(info);
+            }
+        }));
+    });
+}
+
+fn payload_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(s) = payload.downcast_ref::<&str>() {
+        s.to_string()
+    } else if let Some(s) = payload.downcast_ref::<String>() {
+        s.clone()
+    } else {
+        "Box<dyn Any>".to_string()
+    }
+}
+
+/// Run one stage of a day, turning a panic into a [`Failure`].
+pub fn catch<T>(stage: &'static str, f: impl FnOnce() -> T) -> Result<T, Failure> {
+    install_panic_hook();
+
+    let was_catching = CATCHING.replace(true);
+    let result = panic::catch_unwind(AssertUnwindSafe(f));
+    CATCHING.set(was_catching);
+
+    result.map_err(|payload| {
+        let (message, location) = LAST_PANIC
+            .take()
+            .unwrap_or_else(|| (payload_message(payload.as_ref()), None));
+        Failure::Panic {
+            stage,
+            message,
+            location,
+        }
+    })
+}
+
+/// Run one stage that may fail, turning both a panic and an error into a
+/// [`Failure`].
+pub fn attempt<T>(stage: &'static str, f: impl FnOnce() -> AocResult<T>) -> Result<T, Failure> {
+    catch(stage, f).and_then(|result| result.map_err(|error| Failure::Error { stage, error }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn catch_passes_values_through() {
+        assert_eq!(catch("part 1", || 42), Ok(42));
+    }
+
+    #[test]
+    fn catch_records_message_and_location() {
+        let failure = catch("parse", || -> u8 { panic!("bad input {}", 7) }).unwrap_err();
+        let Failure::Panic { stage, message, location } = &failure else {
+            panic!("Expected a panic failure, got {:?}", failure);
+        };
+        assert_eq!(*stage, "parse");
+        assert_eq!(message, "bad input 7");
+        assert!(location.as_deref().unwrap().starts_with("src/failure.rs:"));
+        assert!(failure.to_string().starts_with("parse panicked at src/failure.rs:"));
+    }
+
+    #[test]
+    fn attempt_reports_errors() {
+        assert_eq!(attempt("part 1", || Ok(42)), Ok(42));
+        let failure = attempt("parse", || -> AocResult<u8> {
+            Err(AocError::new("Empty line").on_line(2).for_day(1))
+        })
+        .unwrap_err();
+        assert_eq!(failure.to_string(), "parse failed: day 1, line 2: Empty line");
+    }
+}
@@ -0,0 +1,236 @@
+use std::io;
+use std::path::Path;
+use std::process::Command;
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+use crate::util;
+
+/// Default location of the benchmark history, one JSON record per line.
+pub const HISTORY_PATH: &str = "bench_history.jsonl";
+
+/// Median time per stage for one day, in nanoseconds. A part is missing
+/// when the run only covered the other one (`--part`).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DayTimings {
+    pub day: u8,
+    pub parse_ns: u64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub part1_ns: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub part2_ns: Option<u64>,
+}
+
+impl DayTimings {
+    pub fn new(day: u8, parse: Duration, part1: Option<Duration>, part2: Option<Duration>) -> DayTimings {
+        let nanos = |d: Duration| d.as_nanos() as u64;
+        DayTimings {
+            day,
+            parse_ns: nanos(parse),
+            part1_ns: part1.map(nanos),
+            part2_ns: part2.map(nanos),
+        }
+    }
+
+    fn stages(&self) -> [(&'static str, Option<u64>); 3] {
+        [
+            ("parse", Some(self.parse_ns)),
+            ("part 1", self.part1_ns),
+            ("part 2", self.part2_ns),
+        ]
+    }
+}
+
+/// A single `--bench` / `--stats` invocation.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BenchRun {
+    /// Seconds since the Unix epoch.
+    pub timestamp: u64,
+    /// Short git revision, suffixed with `-dirty` for uncommitted changes.
+    pub revision: String,
+    /// `"bench"` for single samples, `"stats"` for medians over many runs.
+    pub mode: String,
+    pub days: Vec<DayTimings>,
+}
+
+impl BenchRun {
+    pub fn new(mode: &str, days: Vec<DayTimings>) -> BenchRun {
+        BenchRun {
+            timestamp: util::unix_now(),
+            revision: git_revision(),
+            mode: mode.to_string(),
+            days,
+        }
+    }
+}
+
+/// Short hash of `HEAD`, or `"unknown"` outside a git checkout.
+pub fn git_revision() -> String {
+    let git = |args: &[&str]| {
+        Command::new("git")
+            .args(args)
+            .output()
+            .ok()
+            .filter(|out| out.status.success())
+            .map(|out| String::from_utf8_lossy(&out.stdout).trim().to_string())
+    };
+
+    match git(&["rev-parse", "--short", "HEAD"]) {
+        Some(rev) if !rev.is_empty() => {
+            let dirty = git(&["status", "--porcelain", "--untracked-files=no"])
+                .is_some_and(|status| !status.is_empty());
+            if dirty { format!("{}-dirty", rev) } else { rev }
+        }
+        _ => "unknown".to_string(),
+    }
+}
+
+/// Load every recorded run. A missing file is an empty history.
+pub fn load(path: &Path) -> io::Result<Vec<BenchRun>> {
+    util::load_jsonl(path)
+}
+
+pub fn append(path: &Path, run: &BenchRun) -> io::Result<()> {
+    util::append_jsonl(path, run)
+}
+
+/// One stage of one day compared against its baseline.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Comparison {
+    pub day: u8,
+    pub stage: &'static str,
+    pub baseline_revision: String,
+    pub baseline: Duration,
+    pub current: Duration,
+    /// Relative change, e.g. `0.25` for 25% slower.
+    pub change: f64,
+    pub regression: bool,
+}
+
+/// Compare `current` against the most recent earlier run of the same mode
+/// that covers each day, optionally restricted to a given revision.
+///
+/// A stage is a regression when it got slower by more than `threshold`
+/// (a fraction, so `0.1` is 10%).
+pub fn compare(
+    current: &BenchRun,
+    history: &[BenchRun],
+    revision: Option<&str>,
+    threshold: f64,
+) -> Vec<Comparison> {
+    let mut comparisons = Vec::new();
+
+    for day in &current.days {
+        let baseline = history
+            .iter()
+            .rev()
+            .filter(|run| run.mode == current.mode)
+            .filter(|run| revision.is_none_or(|rev| run.revision.starts_with(rev)))
+            .find_map(|run| {
+                run.days
+                    .iter()
+                    .find(|d| d.day == day.day)
+                    .map(|d| (run, d))
+            });
+        let Some((base_run, base_day)) = baseline else {
+            continue;
+        };
+
+        for ((stage, now), (_, before)) in day.stages().into_iter().zip(base_day.stages()) {
+            // Only compare stages that both runs measured
+            let (Some(now), Some(before)) = (now, before) else {
+                continue;
+            };
+            let change = if before == 0 {
+                0.0
+            } else {
+                now as f64 / before as f64 - 1.0
+            };
+            comparisons.push(Comparison {
+                day: day.day,
+                stage,
+                baseline_revision: base_run.revision.clone(),
+                baseline: Duration::from_nanos(before),
+                current: Duration::from_nanos(now),
+                change,
+                regression: change > threshold,
+            });
+        }
+    }
+
+    comparisons
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run(revision: &str, mode: &str, days: Vec<DayTimings>) -> BenchRun {
+        BenchRun {
+            timestamp: 0,
+            revision: revision.to_string(),
+            mode: mode.to_string(),
+            days,
+        }
+    }
+
+    fn timings(day: u8, parse: u64, part1: u64, part2: u64) -> DayTimings {
+        DayTimings {
+            day,
+            parse_ns: parse,
+            part1_ns: Some(part1),
+            part2_ns: Some(part2),
+        }
+    }
+
+    #[test]
+    fn compare_flags_regressions_over_threshold() {
+        let history = vec![run("abc123", "stats", vec![timings(2, 100, 1000, 1000)])];
+        let current = run("def456", "stats", vec![timings(2, 105, 1200, 900)]);
+
+        let result = compare(&current, &history, None, 0.1);
+        let flagged: Vec<_> = result.iter().filter(|c| c.regression).map(|c| c.stage).collect();
+        assert_eq!(flagged, vec!["part 1"]);
+        assert_eq!(result[0].baseline_revision, "abc123");
+    }
+
+    #[test]
+    fn compare_uses_latest_matching_baseline() {
+        let history = vec![
+            run("aaa", "stats", vec![timings(1, 10, 10, 10)]),
+            run("bbb", "bench", vec![timings(1, 1, 1, 1)]),
+            run("ccc", "stats", vec![timings(2, 10, 10, 10)]),
+        ];
+        let current = run("ddd", "stats", vec![timings(1, 10, 10, 10)]);
+
+        // Skips the bench-mode run and the run that lacks day 1
+        let result = compare(&current, &history, None, 0.1);
+        assert!(result.iter().all(|c| c.baseline_revision == "aaa"));
+
+        // Explicit revision with no matching run gives no comparison
+        assert!(compare(&current, &history, Some("zzz"), 0.1).is_empty());
+    }
+
+    #[test]
+    fn compare_skips_parts_missing_from_either_run() {
+        let history = vec![run("abc123", "stats", vec![timings(4, 100, 1000, 1000)])];
+        let mut only_part2 = timings(4, 100, 0, 2000);
+        only_part2.part1_ns = None;
+        let current = run("def456", "stats", vec![only_part2]);
+
+        let stages: Vec<_> = compare(&current, &history, None, 0.1)
+            .iter()
+            .map(|c| c.stage)
+            .collect();
+        assert_eq!(stages, vec!["parse", "part 2"]);
+    }
+
+    #[test]
+    fn history_round_trips_through_json() {
+        let original = run("abc123-dirty", "bench", vec![timings(5, 1, 2, 3)]);
+        let line = serde_json::to_string(&original).unwrap();
+        let parsed: BenchRun = serde_json::from_str(&line).unwrap();
+        assert_eq!(parsed, original);
+    }
+}
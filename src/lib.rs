@@ -0,0 +1,32 @@
+//! Advent of Code 2025 solutions and the framework for running, checking
+//! and benchmarking them.
+//!
+//! Every `src/dayNN.rs` is exposed as `aoc2025::dayNN` and registered in
+//! [`SOLUTIONS`]; the `aoc2025` binary is a thin CLI over this crate.
+
+pub mod allocs;
+pub mod answers;
+pub mod bench;
+pub mod client;
+pub mod error;
+pub mod failure;
+pub mod history;
+pub mod puzzle;
+pub mod report;
+pub mod runner;
+pub mod solution;
+pub mod submit;
+pub mod table;
+pub mod util;
+pub mod watch;
+
+use solution::DynSolution;
+
+// `pub mod dayNN;` and the `SOLUTIONS` registry for every `src/dayNN.rs`,
+// generated by build.rs
+include!(concat!(env!("OUT_DIR"), "/days.rs"));
+
+/// Look up an implemented day in the registry.
+pub fn find_solution(day: u8) -> Option<&'static dyn DynSolution> {
+    SOLUTIONS.iter().copied().find(|s| s.day() == day)
+}
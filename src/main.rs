@@ -1,21 +1,61 @@
-use clap::Parser;
-// use colored::*;
-use std::time::Instant;
+use clap::error::ErrorKind;
+use clap::{CommandFactory, Parser};
+use colored::Colorize;
+use std::io::Write;
+use std::path::Path;
+use std::time::{Duration, Instant};
 
-// MODULES
-mod day05;
-mod day01;
-mod day02;
-mod day03;
-mod day04;
+use aoc2025::allocs::DayAllocs;
+use aoc2025::answers::{self, Expected, Verdict};
+use aoc2025::bench::{self, BenchConfig, DayStats};
+use aoc2025::failure::Failure;
+use aoc2025::history::{self, BenchRun, DayTimings};
+use aoc2025::report::{self, Format, PartReport, Status};
+use aoc2025::runner::{self, DayResult, InputChoice, InputSource, Outcome, Parts, RunOptions, Solved};
+use aoc2025::table::{self, ColorChoice};
+use aoc2025::util::{format_days, parse_days, parse_duration, DaySet};
+use aoc2025::watch;
 
 #[derive(Parser, Debug)]
 #[command(author, version, about, long_about = None)]
 struct Args {
     /// Day to run
-    #[arg(required = false)]
+    #[arg(required = false, conflicts_with_all = ["all", "days", "from", "to", "latest"])]
     day: Option<u8>,
 
+    /// Run a set of days, e.g. 1-3,5
+    #[arg(long, value_name = "SET", value_parser = parse_days, conflicts_with_all = ["all", "from", "to", "latest"])]
+    days: Option<DaySet>,
+
+    /// Run every day from this one (through --to, or 25)
+    #[arg(long, value_name = "DAY", value_parser = clap::value_parser!(u8).range(1..=25))]
+    from: Option<u8>,
+
+    /// Run every day up to this one (from --from, or 1)
+    #[arg(long, value_name = "DAY", value_parser = clap::value_parser!(u8).range(1..=25))]
+    to: Option<u8>,
+
+    /// Run the highest-numbered implemented day
+    #[arg(long, conflicts_with_all = ["all", "from", "to"])]
+    latest: bool,
+
+    /// Read input from this file instead of inputs/input_dayXX.txt ("-" for stdin)
+    #[arg(long, value_name = "PATH", conflicts_with_all = ["all", "days", "from", "to"])]
+    input: Option<String>,
+
+    /// Check --input against this table of answers/dayXX.toml
+    #[arg(long, value_name = "KEY", requires = "input")]
+    answers_key: Option<String>,
+
+    /// Run against inputs/example_dayXX.txt (or example_dayXX_N.txt) and
+    /// show the expected example answers
+    #[arg(short, long, value_name = "N", conflicts_with = "input")]
+    example: Option<Option<u32>>,
+
+    /// Run against inputs/input_dayXX.txt and every inputs/dayXX/*.txt
+    #[arg(long, conflicts_with_all = ["input", "example"])]
+    all_inputs: bool,
+
     /// Benchmark the solution
     #[arg(short, long)]
     bench: bool,
@@ -23,47 +63,486 @@ struct Args {
     /// Run all days
     #[arg(long)]
     all: bool,
+
+    /// Only run (and benchmark) this part
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: Option<u8>,
+
+    /// Output format; jsonl writes one result per line
+    #[arg(long, value_enum, default_value_t = Format::Text)]
+    format: Format,
+
+    /// When to color the output
+    #[arg(long, value_enum, default_value_t = ColorChoice::Auto)]
+    color: ColorChoice,
+
+    /// Highlight parts slower than this in the results table
+    #[arg(long, default_value = "1s", value_parser = parse_duration)]
+    slow: Duration,
+
+    /// Verify answers against answers/dayXX.toml
+    #[arg(long)]
+    check: bool,
+
+    /// Number of days to run in parallel (0 = one per CPU)
+    #[arg(short, long, default_value_t = 1)]
+    jobs: usize,
+
+    /// Give up on any parse or part that runs longer than this, e.g. 30s
+    #[arg(long, value_parser = parse_duration)]
+    timeout: Option<Duration>,
+
+    /// Statistical benchmark: warm up, then time each stage repeatedly
+    #[arg(long)]
+    stats: bool,
+
+    /// Untimed warmup runs per stage in --stats mode
+    #[arg(long, default_value_t = 3)]
+    warmup: u32,
+
+    /// Fixed number of timed runs per stage in --stats mode (overrides --bench-time)
+    #[arg(long)]
+    iterations: Option<u32>,
+
+    /// Time budget per stage in --stats mode, e.g. 500ms, 2s
+    #[arg(long, default_value = "1s", value_parser = parse_duration)]
+    bench_time: Duration,
+
+    /// Compare timings against the benchmark history and flag regressions
+    #[arg(long)]
+    compare: bool,
+
+    /// Git revision (prefix) to compare against; defaults to the most recent run
+    #[arg(long, requires = "compare")]
+    baseline: Option<String>,
+
+    /// Slowdown, in percent, above which a stage counts as a regression
+    #[arg(long, default_value_t = 10.0)]
+    threshold: f64,
+
+    /// Don't append this run to the benchmark history
+    #[arg(long)]
+    no_history: bool,
+
+    /// Rebuild and re-run the day whenever its source or inputs change
+    #[arg(long, requires = "day", conflicts_with_all = ["all", "format"])]
+    watch: bool,
+}
+
+/// ` (path)` for anything but the day's puzzle input, to tell results for
+/// the same day apart.
+fn input_note(day: u8, input: &str) -> String {
+    if input == InputSource::default_for(day).to_string() {
+        String::new()
+    } else {
+        format!(" ({})", input)
+    }
+}
+
+fn print_result(result: &DayResult, bench: bool) {
+    println!(
+        "Running Day {:02} challenge{}...",
+        result.day,
+        input_note(result.day, &result.input.to_string())
+    );
+    for (part, outcome, _) in result.parts() {
+        match outcome {
+            Ok(answer) => println!("Solution Part {}: {}", part, answer),
+            Err(failure) => println!("Solution Part {}: {} ({})", part, "FAILED".red(), failure),
+        }
+    }
+
+    // A parse error fails every part the same way, so show the input once
+    let diagnostic = result.parts().into_iter().find_map(|(_, outcome, _)| match outcome {
+        Err(Failure::Error { error, .. }) => error.diagnostic(&result.input.to_string()),
+        _ => None,
+    });
+    if let Some(diagnostic) = diagnostic {
+        println!("{}", diagnostic);
+    }
+
+    if bench {
+        let t = &result.timings;
+        println!("Day {} took: {:?}", result.day, t.total());
+        println!("  read:   {:?}", t.read);
+        println!("  parse:  {:?}", t.parse);
+        for (part, _, time) in result.parts() {
+            println!("  part {}: {:?}", part, time);
+        }
+    }
+}
+
+/// Print pass/fail/unknown for each part that was run.
+fn report_check(result: &DayResult, expected: &Expected) {
+    for (part, outcome, _) in result.parts() {
+        let Ok(answer) = outcome else {
+            println!("  Part {}: {} (no answer)", part, "FAILED".red());
+            continue;
+        };
+        let verdict = answers::check(expected.part(part), answer);
+        let label = table::color_status(&verdict.to_string(), Status::from(&verdict));
+        match &verdict {
+            Verdict::Fail { expected } => {
+                println!("  Part {}: {} (expected {}, got {})", part, label, expected, answer)
+            }
+            _ => println!("  Part {}: {} ({})", part, label, answer),
+        }
+    }
+}
+
+fn print_allocs(day: u8, allocs: &DayAllocs) {
+    println!("Day {} allocations:", day);
+    println!("  parse:  {}", allocs.parse);
+    for part in [1, 2] {
+        if let Some(part_allocs) = allocs.part(part) {
+            println!("  part {}: {}", part, part_allocs);
+        }
+    }
+}
+
+fn print_stats(stats: &DayStats) {
+    println!("Day {} statistics:", stats.day);
+    println!("  parse:  {}", stats.parse);
+    for part in [1, 2] {
+        if let Some(part_stats) = stats.part(part) {
+            println!("  part {}: {}", part, part_stats);
+        }
+    }
+}
+
+/// Print the comparison against stored history and return whether any
+/// stage regressed.
+///
+/// In machine-readable modes the report goes to stderr so stdout stays
+/// parseable.
+fn report_comparison(run: &BenchRun, args: &Args) -> bool {
+    macro_rules! say {
+        ($($arg:tt)*) => {
+            if args.format == Format::Text { println!($($arg)*) } else { eprintln!($($arg)*) }
+        };
+    }
+
+    let history = match history::load(Path::new(history::HISTORY_PATH)) {
+        Ok(history) => history,
+        Err(e) => {
+            eprintln!("Failed to read benchmark history: {}", e);
+            return false;
+        }
+    };
+
+    let comparisons = history::compare(
+        run,
+        &history,
+        args.baseline.as_deref(),
+        args.threshold / 100.0,
+    );
+    if comparisons.is_empty() {
+        say!("\nNo baseline found in {} to compare against", history::HISTORY_PATH);
+        return false;
+    }
+
+    say!("\nComparison against stored baseline (threshold {}%):", args.threshold);
+    for c in &comparisons {
+        say!(
+            "  Day {} {:<6} {:>10.2?} -> {:>10.2?} ({:+.1}%) [{}]{}",
+            c.day,
+            c.stage,
+            c.baseline,
+            c.current,
+            c.change * 100.0,
+            c.baseline_revision,
+            if c.regression { "  REGRESSION" } else { "" }
+        );
+    }
+
+    let regressions = comparisons.iter().filter(|c| c.regression).count();
+    if regressions > 0 {
+        say!("{} stage(s) regressed", regressions);
+    }
+    regressions > 0
+}
+
+/// Write machine-readable reports to stdout, returning whether that failed.
+fn write_reports(format: Format, reports: &[PartReport]) -> bool {
+    let mut out = std::io::stdout().lock();
+    match report::write(&mut out, format, reports).and_then(|_| out.flush()) {
+        Ok(()) => false,
+        Err(e) => {
+            eprintln!("Failed to write results: {}", e);
+            true
+        }
+    }
+}
+
+/// Print how many parts succeeded and why the others did not, preceded by
+/// the results table in text mode. Goes to stderr in machine-readable
+/// modes.
+fn print_summary(reports: &[PartReport], solved_parts: usize, failures: &[String], missing: &[u8], args: &Args) {
+    let text = args.format == Format::Text;
+    let mut summary = String::new();
+    if text && !reports.is_empty() {
+        summary.push('\n');
+        summary.push_str(&table::render(reports, args.slow));
+    }
+
+    let counts = format!(
+        "{} part(s) solved, {} failure(s)",
+        solved_parts,
+        failures.len()
+    );
+    let counts = if failures.is_empty() { counts.green() } else { counts.red() };
+    summary.push_str(&format!("\nSummary: {}", counts));
+    if !missing.is_empty() {
+        let note = format!("{} day(s) not implemented: {}", missing.len(), format_days(missing));
+        summary.push_str(&format!(", {}", note.yellow()));
+    }
+    for failure in failures {
+        summary.push_str(&format!("\n  {}", failure.red()));
+    }
+
+    if text {
+        println!("{}", summary);
+    } else {
+        eprintln!("{}", summary);
+    }
+}
+
+/// Re-run `day` in a child process each time one of its files changes,
+/// showing the results table against the previous run's.
+fn watch(day: u8, args: &Args) -> ! {
+    // The child gets the same arguments and reports back as JSON
+    let forwarded: Vec<String> = std::env::args().skip(1).filter(|a| a != "--watch").collect();
+    let mut previous: Vec<PartReport> = Vec::new();
+    let mut changed: Vec<std::path::PathBuf> = Vec::new();
+    let input = args.input.as_deref().map(InputSource::from_arg);
+
+    loop {
+        let before = watch::snapshot(day, input.as_ref());
+        print!("\x1b[2J\x1b[H");
+        println!("{}", format!("Watching day {} (Ctrl-C to stop)", day).bold());
+        for path in &changed {
+            println!("  changed: {}", path.display());
+        }
+        println!("Building and running...");
+        let _ = std::io::stdout().flush();
+
+        let run = watch::run_once(&forwarded);
+        match run.reports {
+            Some(reports) => {
+                println!("{}", table::render_compared(&reports, &previous, args.slow));
+                previous = reports;
+            }
+            None => println!("{}", "No results; see the output below.".red()),
+        }
+        eprint!("{}", run.messages);
+
+        changed = watch::wait_for_change(day, input.as_ref(), &before);
+    }
 }
 
 fn main() {
     let args = Args::parse();
-    
-    let days_to_run = if args.all {
+    args.color.apply();
+    if args.watch {
+        watch(args.day.expect("--watch requires a day"), &args);
+    }
+    let bench = args.bench || args.compare;
+    let text = args.format == Format::Text;
+    // Example and shared-input answers are always checked
+    let check = args.check || args.example.is_some() || args.all_inputs || args.answers_key.is_some();
+
+    if let (Some(from), Some(to)) = (args.from, args.to)
+        && from > to
+    {
+        Args::command()
+            .error(ErrorKind::ArgumentConflict, format!("--from {} is after --to {}", from, to))
+            .exit();
+    }
+
+    let days_to_run: Vec<u8> = if args.all {
          (1..=25).collect()
+    } else if let Some(DaySet(days)) = &args.days {
+        days.clone()
+    } else if args.from.is_some() || args.to.is_some() {
+        (args.from.unwrap_or(1)..=args.to.unwrap_or(25)).collect()
+    } else if args.latest {
+        match aoc2025::SOLUTIONS.last() {
+            Some(solution) => vec![solution.day()],
+            None => {
+                println!("No days are implemented yet");
+                return;
+            }
+        }
     } else if let Some(day) = args.day {
         vec![day]
     } else {
-        println!("Please provide a day to run or use --all");
+        println!("Please provide a day to run, or use --days, --from/--to, --latest or --all");
         return;
     };
 
-    let mut total_time = std::time::Duration::new(0, 0);
-
-    for day in days_to_run {
-        let start = Instant::now();
-        match day {
-            // DISPATCH
-            5 => day05::run(),
-            1 => day01::run(),
-            2 => day02::run(),
-            3 => day03::run(),
-            4 => day04::run(),
-            _ => {
-                if args.all {
-                     continue;
-                } else {
-                   eprintln!("Day {} not implemented or not added to main.rs", day);
+    let options = RunOptions {
+        input: match (&args.input, args.example) {
+            _ if args.all_inputs => InputChoice::All,
+            (Some(path), _) => InputChoice::Source(InputSource::from_arg(path)),
+            (None, Some(n)) => InputChoice::Example(n),
+            (None, None) => InputChoice::Puzzle,
+        },
+        timeout: args.timeout,
+        parts: Parts::from_arg(args.part),
+        stats: args.stats.then_some(BenchConfig {
+            warmup: args.warmup,
+            iterations: args.iterations,
+            budget: args.bench_time,
+        }),
+        count_allocs: bench || args.stats,
+        answers_key: args.answers_key.clone(),
+    };
+    let jobs = match args.jobs {
+        0 => std::thread::available_parallelism().map_or(1, |n| n.get()),
+        n => n,
+    };
+    if bench || args.stats {
+        bench::warn_if_unoptimized();
+        if jobs > 1 {
+            eprintln!("warning: running days in parallel; timings will interfere with each other.");
+        }
+    }
+
+    let wall_clock = Instant::now();
+    let mut total_time = Duration::ZERO;
+    let mut recorded = Vec::new();
+    let mut reports: Vec<PartReport> = Vec::new();
+    let mut solved_parts = 0;
+    let mut failures: Vec<String> = Vec::new();
+    let mut failed = false;
+    let mut missing: Vec<u8> = Vec::new();
+
+    runner::run_ordered(&days_to_run, jobs, |&day| runner::execute_day(day, &options), |&day, outcomes| {
+        for outcome in outcomes {
+            let (result, expected, stats, allocs) = match outcome {
+                Outcome::Missing => {
+                    // Several days are listed together in the summary; a
+                    // single day asked for by name has failed outright
+                    if days_to_run.len() == 1 {
+                        eprintln!("Day {} not implemented (no src/day{:02}.rs)", day, day);
+                        failed = true;
+                    }
+                    missing.push(day);
+                    continue;
+                }
+                Outcome::Failed { input, error } => {
+                    failures.push(format!("Day {}: {}", day, error));
+                    reports.extend(report::error_reports(day, options.parts, input, error));
+                    failed = true;
+                    continue;
+                }
+                Outcome::Solved(solved) => {
+                    let Solved { result, expected, stats, allocs } = *solved;
+                    (result, expected, stats, allocs)
+                }
+            };
+
+            total_time += result.timings.total();
+            if text {
+                print_result(&result, bench);
+            }
+
+            if check && text {
+                println!("Day {} check{}:", day, input_note(day, &result.input.to_string()));
+                report_check(&result, &expected);
+            }
+
+            if let Some(stats) = &stats
+                && text
+            {
+                print_stats(stats);
+            }
+
+            if let Some(allocs) = &allocs
+                && text
+            {
+                print_allocs(day, allocs);
+            }
+
+            // History tracks the puzzle input only, so runs stay comparable
+            let puzzle_input = result.input == InputSource::default_for(day);
+            if let Some(stats) = stats.as_ref().filter(|_| puzzle_input) {
+                recorded.push(DayTimings::new(
+                    day,
+                    stats.parse.median,
+                    stats.part1.as_ref().map(|s| s.median),
+                    stats.part2.as_ref().map(|s| s.median),
+                ));
+            } else if bench && puzzle_input && result.is_ok() {
+                let t = &result.timings;
+                let time = |part: &Option<_>, time| part.as_ref().map(|_| time);
+                recorded.push(DayTimings::new(
+                    day,
+                    t.parse,
+                    time(&result.part1, t.part1),
+                    time(&result.part2, t.part2),
+                ));
+            }
+
+            let day_reports = report::part_reports(&result, &expected, stats.as_ref(), allocs.as_ref());
+            for r in &day_reports {
+                match r.status {
+                    // A wrong answer only counts as a failure when asked to check
+                    Status::Fail if !check => solved_parts += 1,
+                    status if status.is_failure() => {
+                        let reason = r.error.clone().unwrap_or_else(|| {
+                            format!(
+                                "expected {}, got {}",
+                                r.expected.as_deref().unwrap_or("?"),
+                                r.answer.as_deref().unwrap_or("?")
+                            )
+                        });
+                        failures.push(format!(
+                            "Day {} part {}{}: {}",
+                            r.day,
+                            r.part,
+                            input_note(r.day, &r.input),
+                            reason
+                        ));
+                        failed = true;
+                    }
+                    _ => solved_parts += 1,
                 }
             }
+            reports.extend(day_reports);
+
+            // JSON Lines streams each day's results as soon as they are ready
+            if args.format == Format::Jsonl {
+                failed |= write_reports(args.format, &reports);
+                reports.clear();
+            }
         }
-        let duration = start.elapsed();
-        if args.bench {
-            println!("Day {} took: {:?}", day, duration);
-            total_time += duration;
+    });
+    let wall_clock = wall_clock.elapsed();
+
+    if bench && days_to_run.len() > 1 && text {
+        println!("\nTotal time: {:?}", total_time);
+        println!("Wall-clock time: {:?} ({} job{})", wall_clock, jobs, if jobs == 1 { "" } else { "s" });
+    }
+
+    failed |= write_reports(args.format, &reports);
+
+    if days_to_run.len() > 1 || args.all_inputs || check || !failures.is_empty() {
+        print_summary(&reports, solved_parts, &failures, &missing, &args);
+    }
+
+    if !recorded.is_empty() {
+        let run = BenchRun::new(if args.stats { "stats" } else { "bench" }, recorded);
+        failed |= args.compare && report_comparison(&run, &args);
+
+        if !args.no_history
+            && let Err(e) = history::append(Path::new(history::HISTORY_PATH), &run)
+        {
+            eprintln!("Failed to write benchmark history: {}", e);
         }
     }
-    
-    if args.bench && args.all {
-        println!("\nTotal time: {:?}", total_time);
+
+    if failed {
+        std::process::exit(1);
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,272 @@
+use std::path::PathBuf;
+
+use crate::answers::Expected;
+
+/// Directory holding one `dayXX.md` per downloaded puzzle description.
+pub const PUZZLES_DIR: &str = "puzzles";
+
+pub fn puzzle_path(day: u8) -> PathBuf {
+    PathBuf::from(PUZZLES_DIR).join(format!("day{:02}.md", day))
+}
+
+/// What we keep from a puzzle page.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Puzzle {
+    /// Every part's description, as markdown.
+    pub markdown: String,
+    /// The contents of each `<pre><code>` block, in page order.
+    pub examples: Vec<String>,
+    /// The last emphasized inline code of each part's description, which is
+    /// where the puzzle states the example's answer.
+    pub example_answers: Expected,
+}
+
+/// The inner HTML of each `<article>` on the page: one per unlocked part.
+fn articles(html: &str) -> Vec<&str> {
+    let mut articles = Vec::new();
+    let mut rest = html;
+    while let Some((_, after)) = rest.split_once("<article") {
+        let Some((_, body)) = after.split_once('>') else { break };
+        let Some((inner, after)) = body.split_once("</article>") else { break };
+        articles.push(inner);
+        rest = after;
+    }
+    articles
+}
+
+fn unescape(text: &str) -> String {
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&")
+}
+
+fn strip_tags(html: &str) -> String {
+    let mut text = String::new();
+    let mut in_tag = false;
+    for c in html.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    unescape(&text)
+}
+
+/// Split `<name attrs>` into its lowercase name, whether it closes, and
+/// the value of its `href`, if any.
+fn parse_tag(tag: &str) -> (String, bool, Option<String>) {
+    let tag = tag.trim_matches(|c| c == '<' || c == '>').trim_end_matches('/');
+    let (closing, tag) = match tag.strip_prefix('/') {
+        Some(tag) => (true, tag),
+        None => (false, tag),
+    };
+    let name = tag
+        .split_whitespace()
+        .next()
+        .unwrap_or("")
+        .to_ascii_lowercase();
+    let href = tag
+        .split_once("href=\"")
+        .and_then(|(_, rest)| rest.split_once('"'))
+        .map(|(href, _)| unescape(href));
+    (name, closing, href)
+}
+
+/// Convert one article to markdown. Only the tags the puzzle pages use are
+/// handled; anything else is dropped, keeping its text.
+fn article_markdown(html: &str) -> String {
+    let mut out = String::new();
+    let mut rest = html;
+    let mut links = Vec::new();
+
+    while !rest.is_empty() {
+        let Some(start) = rest.find('<') else {
+            out.push_str(&unescape(&rest.replace('\n', " ")));
+            break;
+        };
+        out.push_str(&unescape(&rest[..start].replace('\n', " ")));
+        let Some(end) = rest[start..].find('>') else { break };
+        let tag = &rest[start..start + end + 1];
+        rest = &rest[start + end + 1..];
+
+        let (name, closing, href) = parse_tag(tag);
+        match (name.as_str(), closing) {
+            ("h2", false) => out.push_str("## "),
+            ("h2", true) | ("p", true) | ("ul", true) => out.push_str("\n\n"),
+            ("li", false) => out.push_str("- "),
+            ("li", true) => out.push('\n'),
+            ("em", _) => out.push_str("**"),
+            ("a", false) => {
+                out.push('[');
+                links.push(href.unwrap_or_default());
+            }
+            ("a", true) => out.push_str(&format!("]({})", links.pop().unwrap_or_default())),
+            ("pre", false) => {
+                let (block, after) = rest.split_once("</pre>").unwrap_or((rest, ""));
+                let code = strip_tags(block);
+                out.push_str("```\n");
+                out.push_str(&code);
+                if !code.ends_with('\n') {
+                    out.push('\n');
+                }
+                out.push_str("```\n\n");
+                rest = after;
+            }
+            ("code", false) => {
+                let (inner, after) = rest.split_once("</code>").unwrap_or((rest, ""));
+                let code = format!("`{}`", strip_tags(inner));
+                if inner.contains("<em>") {
+                    out.push_str(&format!("**{}**", code));
+                } else {
+                    out.push_str(&code);
+                }
+                rest = after;
+            }
+            _ => {}
+        }
+    }
+
+    // Tidy the spacing left around block elements, leaving code blocks as
+    // they are
+    let mut markdown = String::new();
+    let mut in_code = false;
+    let mut blank = true;
+    for line in out.lines() {
+        if line.trim_start().starts_with("```") {
+            in_code = !in_code;
+        } else if in_code {
+            markdown.push_str(line);
+            markdown.push('\n');
+            continue;
+        }
+
+        let line = line.trim();
+        if line.is_empty() {
+            if !blank {
+                markdown.push('\n');
+            }
+            blank = true;
+        } else {
+            markdown.push_str(line);
+            markdown.push('\n');
+            blank = false;
+        }
+    }
+    markdown.trim_end().to_string()
+}
+
+/// The text of every `<pre><code>` block in an article.
+fn code_blocks(html: &str) -> Vec<String> {
+    let mut blocks = Vec::new();
+    let mut rest = html;
+    while let Some((_, after)) = rest.split_once("<pre><code>") {
+        let (block, after) = after.split_once("</code></pre>").unwrap_or((after, ""));
+        let mut text = strip_tags(block);
+        if !text.ends_with('\n') {
+            text.push('\n');
+        }
+        blocks.push(text);
+        rest = after;
+    }
+    blocks
+}
+
+/// The last `<code><em>…</em></code>` in an article.
+fn emphasized_answer(html: &str) -> Option<String> {
+    let (_, after) = html.rsplit_once("<code><em>")?;
+    let (answer, _) = after.split_once("</em></code>")?;
+    Some(strip_tags(answer).trim().to_string()).filter(|a| !a.is_empty())
+}
+
+/// Pull the description, examples and example answers out of a puzzle
+/// page.
+pub fn parse_page(html: &str) -> Puzzle {
+    let articles = articles(html);
+    let markdown = articles
+        .iter()
+        .map(|a| article_markdown(a))
+        .collect::<Vec<_>>()
+        .join("\n\n");
+
+    Puzzle {
+        markdown: markdown + "\n",
+        examples: articles.iter().flat_map(|a| code_blocks(a)).collect(),
+        example_answers: Expected {
+            part1: articles.first().and_then(|a| emphasized_answer(a)),
+            part2: articles.get(1).and_then(|a| emphasized_answer(a)),
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PAGE: &str = r#"<!DOCTYPE html>
+<html><body><main>
+<article class="day-desc"><h2>--- Day 4: Printing Department ---</h2><p>The rolls of paper (<code>@</code>) are arranged on a <a href="https://en.wikipedia.org/wiki/Grid">grid</a>:</p>
+<pre><code>..@@
+
+  @.&lt;@
+</code></pre>
+<p>For example:</p>
+<ul>
+<li>Rolls with <em>fewer than four</em> neighbours can be reached.</li>
+</ul>
+<p>In this example, there are <code><em>13</em></code> rolls of paper that can be accessed.</p>
+</article>
+<p>Your puzzle answer was <code>1234</code>.</p>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2><pre><code>x<em>y</em>z</code></pre><p>In total, <code><em>43</em></code> rolls can be removed.</p>
+</article>
+</main></body></html>"#;
+
+    #[test]
+    fn parse_page_extracts_examples_and_answers() {
+        let puzzle = parse_page(PAGE);
+        assert_eq!(puzzle.examples, vec!["..@@\n\n  @.<@\n".to_string(), "xyz\n".to_string()]);
+        assert_eq!(puzzle.example_answers.part(1), Some("13"));
+        assert_eq!(puzzle.example_answers.part(2), Some("43"));
+    }
+
+    #[test]
+    fn parse_page_converts_articles_to_markdown() {
+        let markdown = parse_page(PAGE).markdown;
+        let expected = "\
+## --- Day 4: Printing Department ---
+
+The rolls of paper (`@`) are arranged on a [grid](https://en.wikipedia.org/wiki/Grid):
+
+```
+..@@
+
+  @.<@
+```
+
+For example:
+
+- Rolls with **fewer than four** neighbours can be reached.
+
+In this example, there are **`13`** rolls of paper that can be accessed.
+
+## --- Part Two ---
+
+```
+xyz
+```
+
+In total, **`43`** rolls can be removed.
+";
+        assert_eq!(markdown, expected);
+    }
+
+    #[test]
+    fn page_without_articles_is_empty() {
+        let puzzle = parse_page("<html>Please log in</html>");
+        assert!(puzzle.examples.is_empty());
+        assert_eq!(puzzle.example_answers, Expected::default());
+    }
+}
@@ -0,0 +1,299 @@
+use std::io::{self, Write};
+use std::time::Duration;
+
+use clap::ValueEnum;
+use serde::{Deserialize, Serialize};
+
+use crate::allocs::{Allocs, DayAllocs};
+use crate::answers::{self, Expected, Verdict};
+use crate::bench::{DayStats, Stats};
+use crate::failure::Failure;
+use crate::runner::{DayResult, Parts};
+
+/// How results are written to stdout.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Format {
+    /// Human-readable text
+    Text,
+    /// A single JSON array of part results
+    Json,
+    /// One JSON object per part result, per line
+    Jsonl,
+}
+
+/// Verification status of one part.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Status {
+    Pass,
+    Fail,
+    Unknown,
+    Error,
+    Panic,
+    Timeout,
+}
+
+impl Status {
+    /// Whether this status should make the run exit unsuccessfully.
+    pub fn is_failure(self) -> bool {
+        matches!(self, Status::Fail | Status::Error | Status::Panic | Status::Timeout)
+    }
+}
+
+impl From<&Failure> for Status {
+    fn from(failure: &Failure) -> Self {
+        match failure {
+            Failure::Panic { .. } => Status::Panic,
+            Failure::Timeout { .. } => Status::Timeout,
+            Failure::Error { .. } => Status::Error,
+        }
+    }
+}
+
+impl From<&Verdict> for Status {
+    fn from(verdict: &Verdict) -> Self {
+        match verdict {
+            Verdict::Pass => Status::Pass,
+            Verdict::Fail { .. } => Status::Fail,
+            Verdict::Unknown => Status::Unknown,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PartTimings {
+    pub read_ns: u64,
+    pub parse_ns: u64,
+    pub solve_ns: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StatsReport {
+    pub samples: usize,
+    pub min_ns: u64,
+    pub median_ns: u64,
+    pub mean_ns: u64,
+    pub stddev_ns: u64,
+    pub max_ns: u64,
+    pub low_outliers: usize,
+    pub high_outliers: usize,
+}
+
+impl From<&Stats> for StatsReport {
+    fn from(stats: &Stats) -> Self {
+        StatsReport {
+            samples: stats.samples,
+            min_ns: nanos(stats.min),
+            median_ns: nanos(stats.median),
+            mean_ns: nanos(stats.mean),
+            stddev_ns: nanos(stats.stddev),
+            max_ns: nanos(stats.max),
+            low_outliers: stats.low_outliers,
+            high_outliers: stats.high_outliers,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PartStats {
+    pub parse: StatsReport,
+    pub solve: StatsReport,
+}
+
+/// Allocations made by parsing, and by solving this part.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PartAllocs {
+    pub parse: Allocs,
+    pub solve: Allocs,
+}
+
+/// One machine-readable result: a single part of a single day.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PartReport {
+    pub day: u8,
+    pub part: u8,
+    pub input: String,
+    pub answer: Option<String>,
+    pub expected: Option<String>,
+    pub status: Status,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timings: Option<PartTimings>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stats: Option<PartStats>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allocs: Option<PartAllocs>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+fn nanos(d: Duration) -> u64 {
+    d.as_nanos() as u64
+}
+
+/// Build the per-part reports for a solved day.
+pub fn part_reports(
+    result: &DayResult,
+    expected: &Expected,
+    stats: Option<&DayStats>,
+    allocs: Option<&DayAllocs>,
+) -> Vec<PartReport> {
+    let t = &result.timings;
+    result
+        .parts()
+        .into_iter()
+        .map(|(part, outcome, solve_time)| {
+            let (answer, status, error) = match outcome {
+                Ok(answer) => {
+                    let verdict = answers::check(expected.part(part), answer);
+                    (Some(answer.to_string()), Status::from(&verdict), None)
+                }
+                Err(failure) => (None, Status::from(failure), Some(failure.to_string())),
+            };
+            PartReport {
+                day: result.day,
+                part,
+                input: result.input.to_string(),
+                answer,
+                expected: expected.part(part).map(str::to_string),
+                status,
+                timings: Some(PartTimings {
+                    read_ns: nanos(t.read),
+                    parse_ns: nanos(t.parse),
+                    solve_ns: nanos(solve_time),
+                }),
+                stats: stats.and_then(|s| {
+                    Some(PartStats {
+                        parse: StatsReport::from(&s.parse),
+                        solve: StatsReport::from(s.part(part)?),
+                    })
+                }),
+                allocs: allocs.and_then(|a| {
+                    Some(PartAllocs {
+                        parse: a.parse,
+                        solve: *a.part(part)?,
+                    })
+                }),
+                error,
+            }
+        })
+        .collect()
+}
+
+/// Reports for the selected parts of a day that could not be run at all.
+pub fn error_reports(day: u8, parts: Parts, input: String, error: String) -> Vec<PartReport> {
+    parts
+        .numbers()
+        .iter()
+        .map(|&part| PartReport {
+            day,
+            part,
+            input: input.clone(),
+            answer: None,
+            expected: None,
+            status: Status::Error,
+            timings: None,
+            stats: None,
+            allocs: None,
+            error: Some(error.clone()),
+        })
+        .collect()
+}
+
+/// Write reports in a machine-readable format. Text output is handled by
+/// the caller as results arrive.
+pub fn write(out: &mut impl Write, format: Format, reports: &[PartReport]) -> io::Result<()> {
+    match format {
+        Format::Text => Ok(()),
+        Format::Json => {
+            serde_json::to_writer_pretty(&mut *out, reports).map_err(io::Error::other)?;
+            writeln!(out)
+        }
+        Format::Jsonl => {
+            for report in reports {
+                serde_json::to_writer(&mut *out, report).map_err(io::Error::other)?;
+                writeln!(out)?;
+            }
+            Ok(())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::runner::{InputSource, Timings};
+    use crate::solution::Answer;
+
+    fn result() -> DayResult {
+        DayResult {
+            day: 3,
+            input: InputSource::default_for(3),
+            part1: Some(Ok(Answer::UInt(357))),
+            part2: Some(Ok(Answer::UInt(42))),
+            timings: Timings {
+                read: Duration::from_nanos(10),
+                parse: Duration::from_nanos(20),
+                part1: Duration::from_nanos(30),
+                part2: Duration::from_nanos(40),
+            },
+        }
+    }
+
+    #[test]
+    fn part_reports_carry_status_and_timings() {
+        let expected = Expected {
+            part1: Some("357".to_string()),
+            part2: Some("41".to_string()),
+        };
+        let reports = part_reports(&result(), &expected, None, None);
+
+        assert_eq!(reports.len(), 2);
+        assert_eq!(reports[0].status, Status::Pass);
+        assert_eq!(reports[1].status, Status::Fail);
+        assert_eq!(reports[1].timings.as_ref().unwrap().solve_ns, 40);
+    }
+
+    #[test]
+    fn part_reports_mark_panics() {
+        let mut result = result();
+        result.part2 = Some(Err(Failure::Panic {
+            stage: "part 2",
+            message: "boom".to_string(),
+            location: None,
+        }));
+        let reports = part_reports(&result, &Expected::default(), None, None);
+
+        assert_eq!(reports[0].status, Status::Unknown);
+        assert_eq!(reports[1].status, Status::Panic);
+        assert_eq!(reports[1].answer, None);
+        assert_eq!(reports[1].error.as_deref(), Some("part 2 panicked: boom"));
+    }
+
+    #[test]
+    fn reports_cover_only_selected_parts() {
+        let mut result = result();
+        result.part1 = None;
+        let reports = part_reports(&result, &Expected::default(), None, None);
+        assert_eq!(reports.iter().map(|r| r.part).collect::<Vec<_>>(), vec![2]);
+
+        let errors = error_reports(3, Parts::Only(1), "x".to_string(), "gone".to_string());
+        assert_eq!(errors.iter().map(|r| r.part).collect::<Vec<_>>(), vec![1]);
+    }
+
+    #[test]
+    fn jsonl_writes_one_object_per_line() {
+        let reports = part_reports(&result(), &Expected::default(), None, None);
+        let mut out = Vec::new();
+        write(&mut out, Format::Jsonl, &reports).unwrap();
+
+        let text = String::from_utf8(out).unwrap();
+        let lines: Vec<&str> = text.lines().collect();
+        assert_eq!(lines.len(), 2);
+        let first: serde_json::Value = serde_json::from_str(lines[0]).unwrap();
+        assert_eq!(first["day"], 3);
+        assert_eq!(first["part"], 1);
+        assert_eq!(first["answer"], "357");
+        assert_eq!(first["status"], "unknown");
+        assert_eq!(first["input"], "inputs/input_day03.txt");
+    }
+}
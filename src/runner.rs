@@ -0,0 +1,883 @@
+use std::any::Any;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::io::{self, Read};
+use std::path::{Component, Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError};
+use std::sync::Arc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::answers::{self, Expected};
+use crate::allocs::{self, DayAllocs};
+use crate::bench::{self, BenchConfig, DayStats};
+use crate::failure::{self, Failure};
+use crate::solution::{Answer, DynSolution};
+
+/// Wall-clock time spent in each stage of a single day.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Timings {
+    pub read: Duration,
+    pub parse: Duration,
+    pub part1: Duration,
+    pub part2: Duration,
+}
+
+impl Timings {
+    pub fn total(&self) -> Duration {
+        self.read + self.parse + self.part1 + self.part2
+    }
+}
+
+/// Answer, or the reason there is none, for a single part.
+pub type PartResult = Result<Answer, Failure>;
+
+/// Which parts of a day to solve.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Parts {
+    #[default]
+    Both,
+    /// Only part 1 or only part 2.
+    Only(u8),
+}
+
+impl Parts {
+    /// From a `--part` argument; no argument means both parts.
+    pub fn from_arg(part: Option<u8>) -> Parts {
+        part.map_or(Parts::Both, Parts::Only)
+    }
+
+    pub fn includes(self, part: u8) -> bool {
+        match self {
+            Parts::Both => true,
+            Parts::Only(only) => only == part,
+        }
+    }
+
+    /// Selected part numbers, in order.
+    pub fn numbers(self) -> &'static [u8] {
+        match self {
+            Parts::Both => &[1, 2],
+            Parts::Only(1) => &[1],
+            Parts::Only(_) => &[2],
+        }
+    }
+}
+
+/// Answers and timings from running one day.
+///
+/// A part that was not selected has no result and a zero solve time.
+#[derive(Debug, Clone)]
+pub struct DayResult {
+    pub day: u8,
+    pub input: InputSource,
+    pub part1: Option<PartResult>,
+    pub part2: Option<PartResult>,
+    pub timings: Timings,
+}
+
+impl DayResult {
+    /// Part number, result and solve time for each part that was run.
+    pub fn parts(&self) -> Vec<(u8, &PartResult, Duration)> {
+        [
+            (1, &self.part1, self.timings.part1),
+            (2, &self.part2, self.timings.part2),
+        ]
+        .into_iter()
+        .filter_map(|(part, result, time)| Some((part, result.as_ref()?, time)))
+        .collect()
+    }
+
+    /// Whether every part that was run produced an answer.
+    pub fn is_ok(&self) -> bool {
+        self.parts().iter().all(|(_, result, _)| result.is_ok())
+    }
+}
+
+pub fn input_path(day: u8) -> PathBuf {
+    PathBuf::from(format!("inputs/input_day{:02}.txt", day))
+}
+
+/// `inputs/example_dayXX.txt`, or `inputs/example_dayXX_N.txt` for the
+/// `n`th example.
+pub fn example_path(day: u8, n: Option<u32>) -> PathBuf {
+    match n {
+        Some(n) => PathBuf::from(format!("inputs/example_day{:02}_{}.txt", day, n)),
+        None => PathBuf::from(format!("inputs/example_day{:02}.txt", day)),
+    }
+}
+
+/// Directory of additional named inputs for a day, e.g. other people's
+/// puzzle inputs: `inputs/dayXX/<name>.txt`.
+pub fn inputs_dir(day: u8) -> PathBuf {
+    PathBuf::from(format!("inputs/day{:02}", day))
+}
+
+/// `unnumbered` unless it is missing or empty and `first` exists.
+fn first_example(unnumbered: PathBuf, first: PathBuf) -> PathBuf {
+    let filled = fs::metadata(&unnumbered).is_ok_and(|m| m.len() > 0);
+    if !filled && first.exists() {
+        first
+    } else {
+        unnumbered
+    }
+}
+
+/// Where a day's puzzle input comes from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    File(PathBuf),
+    Stdin,
+}
+
+impl InputSource {
+    /// Parse a `--input` argument: `-` means stdin, anything else is a path.
+    pub fn from_arg(arg: &str) -> InputSource {
+        if arg == "-" {
+            InputSource::Stdin
+        } else {
+            InputSource::File(PathBuf::from(arg))
+        }
+    }
+
+    /// The standard `inputs/input_dayXX.txt` for a day.
+    pub fn default_for(day: u8) -> InputSource {
+        InputSource::File(input_path(day))
+    }
+
+    /// An example input for a day; see [`example_path`]. Without `n`,
+    /// falls back to the first numbered example when the unnumbered one is
+    /// missing or still empty from `scaffold`.
+    pub fn example(day: u8, n: Option<u32>) -> InputSource {
+        let path = example_path(day, n);
+        match n {
+            Some(_) => InputSource::File(path),
+            None => InputSource::File(first_example(path, example_path(day, Some(1)))),
+        }
+    }
+
+    /// The default input, if present, followed by every `.txt` file in
+    /// [`inputs_dir`] in name order.
+    pub fn all_for(day: u8) -> io::Result<Vec<InputSource>> {
+        let mut named = Vec::new();
+        match fs::read_dir(inputs_dir(day)) {
+            Ok(entries) => {
+                for entry in entries {
+                    let path = entry?.path();
+                    if path.extension().is_some_and(|ext| ext == "txt") {
+                        named.push(path);
+                    }
+                }
+            }
+            Err(e) if e.kind() == io::ErrorKind::NotFound => {}
+            Err(e) => return Err(e),
+        }
+        named.sort();
+
+        let default = input_path(day);
+        Ok(default
+            .exists()
+            .then_some(default)
+            .into_iter()
+            .chain(named)
+            .map(InputSource::File)
+            .collect())
+    }
+
+    /// Key into `answers/dayXX.toml` for this input.
+    ///
+    /// Only inputs the repo knows about have a key: the default input maps
+    /// to `[input]`, `example_day04_2.txt` to `[example_2]` and
+    /// `inputs/day04/alice.txt` to `[alice]`. Any other file, and stdin,
+    /// has no recorded answers unless a key is given explicitly. Neither
+    /// do named inputs called `input`, `example` or `example_N`, whose
+    /// tables belong to the default input and the examples.
+    pub fn answers_key(&self, day: u8) -> Option<String> {
+        let InputSource::File(path) = self else {
+            return None;
+        };
+        // `./inputs/...` names the same file as `inputs/...`
+        let path: PathBuf = path.components().filter(|c| *c != Component::CurDir).collect();
+        if path.extension().is_none_or(|ext| ext != "txt") {
+            return None;
+        }
+        let stem = path.file_stem()?.to_string_lossy();
+        let parent = path.parent()?;
+
+        if path == input_path(day) {
+            Some(answers::DEFAULT_INPUT.to_string())
+        } else if parent == Path::new("inputs") && is_numbered(&stem, &format!("example_day{:02}", day)) {
+            Some(stem.replacen(&format!("_day{:02}", day), "", 1))
+        } else if parent == inputs_dir(day) && stem != answers::DEFAULT_INPUT && !is_numbered(&stem, "example") {
+            Some(stem.into_owned())
+        } else {
+            None
+        }
+    }
+}
+
+/// Whether `stem` is `name` or `name_N`.
+fn is_numbered(stem: &str, name: &str) -> bool {
+    stem == name
+        || stem
+            .strip_prefix(name)
+            .and_then(|rest| rest.strip_prefix('_'))
+            .is_some_and(|n| n.parse::<u32>().is_ok())
+}
+
+impl fmt::Display for InputSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputSource::File(path) => write!(f, "{}", path.display()),
+            InputSource::Stdin => write!(f, "<stdin>"),
+        }
+    }
+}
+
+/// Puzzle input text, read once and shared by every consumer.
+#[derive(Debug, Clone)]
+pub struct Input {
+    pub source: InputSource,
+    pub text: String,
+    pub read_time: Duration,
+}
+
+impl Input {
+    pub fn read(source: InputSource) -> io::Result<Input> {
+        let start = Instant::now();
+        let text = match &source {
+            InputSource::File(path) => fs::read_to_string(path).map_err(|e| {
+                io::Error::new(e.kind(), format!("Failed to read input file {}: {}", path.display(), e))
+            })?,
+            InputSource::Stdin => {
+                let mut text = String::new();
+                io::stdin().read_to_string(&mut text)?;
+                text
+            }
+        };
+        let read_time = start.elapsed();
+
+        Ok(Input {
+            source,
+            text,
+            read_time,
+        })
+    }
+}
+
+/// Result and duration of the parse stage and each selected part.
+struct Stages {
+    parse: Duration,
+    part1: Option<(PartResult, Duration)>,
+    part2: Option<(PartResult, Duration)>,
+}
+
+impl Stages {
+    /// Every selected part fails because parsing did.
+    fn parse_failed(parse: Duration, failure: Failure, parts: Parts) -> Stages {
+        let failed = |part| parts.includes(part).then(|| (Err(failure.clone()), Duration::ZERO));
+        Stages {
+            parse,
+            part1: failed(1),
+            part2: failed(2),
+        }
+    }
+}
+
+fn part_name(part: u8) -> &'static str {
+    if part == 1 { "part 1" } else { "part 2" }
+}
+
+/// Solve one part. Errors get the line they point at from `text`, as
+/// parse errors do, so they can be shown with a caret.
+fn solve_part(solution: &dyn DynSolution, text: &str, parsed: &dyn Any, part: u8) -> PartResult {
+    failure::attempt(part_name(part), || {
+        match part {
+            1 => solution.part1(parsed),
+            _ => solution.part2(parsed),
+        }
+        .map_err(|e| e.with_source(text))
+    })
+}
+
+/// Run every selected stage on the calling thread.
+fn run_inline(solution: &dyn DynSolution, text: &str, parts: Parts) -> Stages {
+    let start = Instant::now();
+    let parsed = failure::attempt("parse", || solution.parse(text));
+    let parse = start.elapsed();
+
+    let parsed = match parsed {
+        Ok(parsed) => parsed,
+        Err(failure) => return Stages::parse_failed(parse, failure, parts),
+    };
+
+    let timed_part = |part| {
+        parts.includes(part).then(|| {
+            let start = Instant::now();
+            let result = solve_part(solution, text, parsed.as_ref(), part);
+            (result, start.elapsed())
+        })
+    };
+    Stages {
+        parse,
+        part1: timed_part(1),
+        part2: timed_part(2),
+    }
+}
+
+/// Progress reported by a stage thread.
+enum Event {
+    Parsed(Result<(), Failure>, Duration),
+    Solved(PartResult, Duration),
+}
+
+/// Parse and then solve `parts` in order on a detached thread.
+///
+/// The thread is never joined: if a stage hangs, the watcher gives up on
+/// it and the thread is left to die with the process.
+fn spawn_stages(solution: &'static dyn DynSolution, text: Arc<str>, parts: &'static [u8]) -> Receiver<Event> {
+    let (tx, rx) = mpsc::channel();
+    thread::Builder::new()
+        .name(format!("day{:02}-stages", solution.day()))
+        .spawn(move || {
+            let start = Instant::now();
+            let parsed = failure::attempt("parse", || solution.parse(&text));
+            let status = parsed.as_ref().map(|_| ()).map_err(Failure::clone);
+            if tx.send(Event::Parsed(status, start.elapsed())).is_err() {
+                return;
+            }
+            let Ok(parsed) = parsed else { return };
+
+            for &part in parts {
+                let start = Instant::now();
+                let result = solve_part(solution, &text, parsed.as_ref(), part);
+                if tx.send(Event::Solved(result, start.elapsed())).is_err() {
+                    return;
+                }
+            }
+        })
+        .expect("Failed to spawn stage thread");
+    rx
+}
+
+/// Wait up to `limit` for the next event from a stage thread.
+fn wait_for(rx: &Receiver<Event>, stage: &'static str, limit: Duration) -> Result<Event, Failure> {
+    rx.recv_timeout(limit).map_err(|e| match e {
+        RecvTimeoutError::Timeout => Failure::Timeout { stage, limit },
+        RecvTimeoutError::Disconnected => Failure::Panic {
+            stage,
+            message: "stage thread exited without a result".to_string(),
+            location: None,
+        },
+    })
+}
+
+fn wait_for_part(rx: &Receiver<Event>, part: u8, limit: Duration) -> (PartResult, Duration) {
+    match wait_for(rx, part_name(part), limit) {
+        Ok(Event::Solved(result, time)) => (result, time),
+        Ok(Event::Parsed(..)) => unreachable!("Stage threads parse exactly once"),
+        Err(failure) => (Err(failure), limit),
+    }
+}
+
+/// Run the selected stages on a watched thread, giving each at most
+/// `limit`.
+///
+/// If part 1 times out its thread is abandoned and part 2 gets a fresh
+/// thread (and a fresh parse), so one pathological part doesn't hide the
+/// other's answer.
+fn run_watched(solution: &'static dyn DynSolution, text: &str, limit: Duration, parts: Parts) -> Stages {
+    let text: Arc<str> = Arc::from(text);
+    let rx = spawn_stages(solution, Arc::clone(&text), parts.numbers());
+
+    let (parsed, parse) = match wait_for(&rx, "parse", limit) {
+        Ok(Event::Parsed(parsed, time)) => (parsed, time),
+        Ok(Event::Solved(..)) => unreachable!("Stage threads parse first"),
+        Err(failure) => (Err(failure), limit),
+    };
+    if let Err(failure) = parsed {
+        return Stages::parse_failed(parse, failure, parts);
+    }
+
+    let part1 = parts.includes(1).then(|| wait_for_part(&rx, 1, limit));
+    let part2 = parts.includes(2).then(|| {
+        if matches!(part1, Some((Err(Failure::Timeout { .. }), _))) {
+            let rx = spawn_stages(solution, text, &[2]);
+            match wait_for(&rx, "parse", limit) {
+                Ok(Event::Parsed(Ok(()), _)) => wait_for_part(&rx, 2, limit),
+                Ok(Event::Parsed(Err(failure), _)) | Err(failure) => (Err(failure), Duration::ZERO),
+                Ok(Event::Solved(..)) => unreachable!("Stage threads parse first"),
+            }
+        } else {
+            wait_for_part(&rx, 2, limit)
+        }
+    });
+
+    Stages {
+        parse,
+        part1,
+        part2,
+    }
+}
+
+/// Parse and solve the selected parts of a day from already-read input,
+/// timing each stage separately.
+///
+/// A panic in any stage is caught and reported as that part's
+/// [`Failure`]; if parsing fails, every selected part carries the parse
+/// failure. With a `timeout`, any stage running longer than it is
+/// abandoned and reported as [`Failure::Timeout`].
+pub fn run_day(
+    solution: &'static dyn DynSolution,
+    input: &Input,
+    timeout: Option<Duration>,
+    parts: Parts,
+) -> DayResult {
+    let stages = match timeout {
+        Some(limit) => run_watched(solution, &input.text, limit, parts),
+        None => run_inline(solution, &input.text, parts),
+    };
+    let (part1, part1_time) = stages.part1.unzip();
+    let (part2, part2_time) = stages.part2.unzip();
+
+    DayResult {
+        day: solution.day(),
+        input: input.source.clone(),
+        part1,
+        part2,
+        timings: Timings {
+            read: input.read_time,
+            parse: stages.parse,
+            part1: part1_time.unwrap_or_default(),
+            part2: part2_time.unwrap_or_default(),
+        },
+    }
+}
+
+/// Which input each day is run against.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum InputChoice {
+    /// `inputs/input_dayXX.txt`
+    #[default]
+    Puzzle,
+    /// The day's example input; see [`InputSource::example`].
+    Example(Option<u32>),
+    /// The same explicit file (or stdin) for every day.
+    Source(InputSource),
+    /// Every input of the day; see [`InputSource::all_for`].
+    All,
+}
+
+impl InputChoice {
+    pub fn sources_for(&self, day: u8) -> io::Result<Vec<InputSource>> {
+        Ok(match self {
+            InputChoice::Puzzle => vec![InputSource::default_for(day)],
+            InputChoice::Example(n) => vec![InputSource::example(day, *n)],
+            InputChoice::Source(source) => vec![source.clone()],
+            InputChoice::All => InputSource::all_for(day)?,
+        })
+    }
+}
+
+/// How to run a day beyond the defaults.
+#[derive(Debug, Clone, Default)]
+pub struct RunOptions {
+    pub input: InputChoice,
+    /// Per-stage watchdog; see [`run_day`].
+    pub timeout: Option<Duration>,
+    /// Parts to solve (and benchmark).
+    pub parts: Parts,
+    /// Benchmark days that solved cleanly with these settings.
+    pub stats: Option<BenchConfig>,
+    /// Count each stage's allocations for days that solved cleanly. Only
+    /// has an effect when [`allocs::ENABLED`].
+    pub count_allocs: bool,
+    /// Table of `answers/dayXX.toml` to check against instead of the one
+    /// the input maps to.
+    pub answers_key: Option<String>,
+}
+
+/// A day that ran to completion.
+#[derive(Debug)]
+pub struct Solved {
+    pub result: DayResult,
+    pub expected: Expected,
+    pub stats: Option<DayStats>,
+    pub allocs: Option<DayAllocs>,
+}
+
+/// Everything produced by running one day, ready to be reported.
+#[derive(Debug)]
+pub enum Outcome {
+    /// No solution is registered for the day.
+    Missing,
+    /// The day could not be run at all, e.g. its input was unreadable.
+    Failed { input: String, error: String },
+    Solved(Box<Solved>),
+}
+
+/// Recorded answers for an input of a day, looked up under `key` if given
+/// and otherwise under [`InputSource::answers_key`]. An answers file that
+/// can't be read is an error rather than "nothing recorded", so a broken
+/// file can't make `--check` pass.
+pub fn expected_for(day: u8, source: &InputSource, key: Option<&str>) -> io::Result<Expected> {
+    match key.map(str::to_string).or_else(|| source.answers_key(day)) {
+        Some(key) => answers::expected(day, &key),
+        None => Ok(Expected::default()),
+    }
+}
+
+/// Read, solve and (optionally) benchmark one day against each of its
+/// selected inputs without printing anything, so days can run on worker
+/// threads.
+pub fn execute_day(day: u8, options: &RunOptions) -> Vec<Outcome> {
+    let Some(solution) = crate::find_solution(day) else {
+        return vec![Outcome::Missing];
+    };
+
+    let failed = |error: String| {
+        vec![Outcome::Failed {
+            input: inputs_dir(day).display().to_string(),
+            error,
+        }]
+    };
+    match options.input.sources_for(day) {
+        Ok(sources) if sources.is_empty() => failed(format!(
+            "No inputs found in {} or {}",
+            input_path(day).display(),
+            inputs_dir(day).display()
+        )),
+        Ok(sources) => sources
+            .into_iter()
+            .map(|source| execute_input(solution, source, options))
+            .collect(),
+        Err(e) => failed(format!("Failed to list {}: {}", inputs_dir(day).display(), e)),
+    }
+}
+
+fn execute_input(solution: &'static dyn DynSolution, source: InputSource, options: &RunOptions) -> Outcome {
+    let label = source.to_string();
+    let expected = match expected_for(solution.day(), &source, options.answers_key.as_deref()) {
+        Ok(expected) => expected,
+        Err(e) => {
+            return Outcome::Failed {
+                input: label,
+                error: format!("Failed to read expected answers: {}", e),
+            }
+        }
+    };
+    let input = match Input::read(source) {
+        Ok(input) => input,
+        Err(e) => {
+            return Outcome::Failed {
+                input: label,
+                error: e.to_string(),
+            }
+        }
+    };
+
+    let result = run_day(solution, &input, options.timeout, options.parts);
+    // Benchmarking a day that panics would only panic again
+    let stats = match &options.stats {
+        Some(config) if result.is_ok() => {
+            bench::bench_day(solution, &input.text, config, options.parts).ok()
+        }
+        _ => None,
+    };
+    let allocs = (options.count_allocs && allocs::ENABLED && result.is_ok())
+        .then(|| allocs::count_day(solution, &input.text, options.parts).ok())
+        .flatten();
+    Outcome::Solved(Box::new(Solved {
+        result,
+        expected,
+        stats,
+        allocs,
+    }))
+}
+
+/// Run `work` over `items` on up to `jobs` threads, handing each result to
+/// `emit` in the original order as soon as every earlier item is done.
+///
+/// With `jobs <= 1` everything runs on the calling thread.
+pub fn run_ordered<I, T, W, E>(items: &[I], jobs: usize, work: W, mut emit: E)
+where
+    I: Sync,
+    T: Send,
+    W: Fn(&I) -> T + Sync,
+    E: FnMut(&I, T),
+{
+    if jobs <= 1 || items.len() <= 1 {
+        for item in items {
+            emit(item, work(item));
+        }
+        return;
+    }
+
+    let next = AtomicUsize::new(0);
+    let (tx, rx) = mpsc::channel();
+
+    thread::scope(|scope| {
+        for _ in 0..jobs.min(items.len()) {
+            let tx = tx.clone();
+            let (next, work) = (&next, &work);
+            scope.spawn(move || loop {
+                let i = next.fetch_add(1, Ordering::Relaxed);
+                if i >= items.len() || tx.send((i, work(&items[i]))).is_err() {
+                    break;
+                }
+            });
+        }
+        drop(tx);
+
+        // Buffer out-of-order results until their predecessors arrive
+        let mut pending = BTreeMap::new();
+        let mut next_emit = 0;
+        for (i, result) in rx {
+            pending.insert(i, result);
+            while let Some(result) = pending.remove(&next_emit) {
+                emit(&items[next_emit], result);
+                next_emit += 1;
+            }
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::error::{AocError, AocResult};
+    use crate::solution::Solution;
+
+    /// Panics while parsing when the input is empty, rejects input with a
+    /// `!` in it, and always panics in part 1.
+    struct Fragile;
+
+    impl Solution for Fragile {
+        const DAY: u8 = 25;
+        type Input = usize;
+
+        fn parse(&self, input: &str) -> AocResult<usize> {
+            assert!(!input.is_empty(), "Empty input");
+            match input.find('!') {
+                Some(i) => Err(AocError::new("Unexpected '!'").on_line(1).at_column(i + 1)),
+                None => Ok(input.len()),
+            }
+        }
+
+        fn part1(&self, _: &usize) -> AocResult<Answer> {
+            panic!("part 1 is broken")
+        }
+
+        fn part2(&self, len: &usize) -> AocResult<Answer> {
+            Ok((*len).into())
+        }
+    }
+
+    fn text_input(text: &str) -> Input {
+        Input {
+            source: InputSource::Stdin,
+            text: text.to_string(),
+            read_time: Duration::ZERO,
+        }
+    }
+
+    #[test]
+    fn run_day_isolates_part_panics() {
+        let result = run_day(&Fragile, &text_input("abc"), None, Parts::Both);
+        let failure = result.part1.unwrap().unwrap_err().to_string();
+        assert!(failure.starts_with("part 1 panicked at src/runner.rs:"));
+        assert!(failure.ends_with("part 1 is broken"));
+        assert_eq!(result.part2, Some(Ok(Answer::UInt(3))));
+    }
+
+    #[test]
+    fn run_day_parse_panic_fails_both_parts() {
+        let result = run_day(&Fragile, &text_input(""), None, Parts::Both);
+        for (_, part, _) in result.parts() {
+            let failure = part.as_ref().unwrap_err().to_string();
+            assert!(failure.starts_with("parse panicked"), "{}", failure);
+        }
+        assert!(!result.is_ok());
+    }
+
+    #[test]
+    fn run_day_reports_parse_errors_with_their_position() {
+        for timeout in [None, Some(Duration::from_secs(60))] {
+            let result = run_day(&Fragile, &text_input("ab!"), timeout, Parts::Both);
+            for (_, part, _) in result.parts() {
+                let failure = part.as_ref().unwrap_err();
+                assert!(matches!(failure, Failure::Error { stage: "parse", .. }));
+                assert_eq!(
+                    failure.to_string(),
+                    "parse failed: day 25, line 1, column 3: Unexpected '!'"
+                );
+            }
+        }
+    }
+
+    /// Parses anything, then finds fault with the second line in part 2.
+    struct Picky;
+
+    impl Solution for Picky {
+        const DAY: u8 = 23;
+        type Input = ();
+
+        fn parse(&self, _: &str) -> AocResult<()> {
+            Ok(())
+        }
+
+        fn part1(&self, _: &()) -> AocResult<Answer> {
+            Ok(1u8.into())
+        }
+
+        fn part2(&self, _: &()) -> AocResult<Answer> {
+            Err(AocError::new("Not allowed").on_line(2).at_column(1))
+        }
+    }
+
+    #[test]
+    fn run_day_part_errors_keep_their_source_line() {
+        for timeout in [None, Some(Duration::from_secs(60))] {
+            let result = run_day(&Picky, &text_input("abc\nxyz\n"), timeout, Parts::Both);
+            let Some(Err(Failure::Error { stage, error })) = result.part2 else {
+                panic!("Expected part 2 to fail with an error");
+            };
+            assert_eq!(stage, "part 2");
+            assert_eq!(error.source_line.as_deref(), Some("xyz"));
+        }
+    }
+
+    /// Part 1 hangs; part 2 answers immediately.
+    struct Stuck;
+
+    impl Solution for Stuck {
+        const DAY: u8 = 24;
+        type Input = ();
+
+        fn parse(&self, _: &str) -> AocResult<()> {
+            Ok(())
+        }
+
+        fn part1(&self, _: &()) -> AocResult<Answer> {
+            thread::sleep(Duration::from_secs(600));
+            Ok(0u8.into())
+        }
+
+        fn part2(&self, _: &()) -> AocResult<Answer> {
+            Ok(2u8.into())
+        }
+    }
+
+    #[test]
+    fn run_day_with_timeout_matches_inline_results() {
+        let result = run_day(&Fragile, &text_input("abc"), Some(Duration::from_secs(60)), Parts::Both);
+        assert!(result.part1.unwrap().unwrap_err().to_string().starts_with("part 1 panicked"));
+        assert_eq!(result.part2, Some(Ok(Answer::UInt(3))));
+    }
+
+    #[test]
+    fn run_day_skips_unselected_part() {
+        for timeout in [None, Some(Duration::from_secs(60))] {
+            let result = run_day(&Fragile, &text_input("abc"), timeout, Parts::Only(2));
+            assert_eq!(result.part1, None);
+            assert_eq!(result.part2, Some(Ok(Answer::UInt(3))));
+            assert_eq!(result.timings.part1, Duration::ZERO);
+            assert!(result.is_ok());
+        }
+    }
+
+    #[test]
+    fn run_day_times_out_hung_part() {
+        let limit = Duration::from_millis(50);
+        let start = Instant::now();
+        let result = run_day(&Stuck, &text_input(""), Some(limit), Parts::Both);
+
+        assert_eq!(result.part1, Some(Err(Failure::Timeout { stage: "part 1", limit })));
+        assert_eq!(result.part2, Some(Ok(Answer::UInt(2))));
+        assert!(start.elapsed() < Duration::from_secs(5));
+    }
+
+    #[test]
+    fn run_ordered_emits_in_input_order() {
+        let items: Vec<u64> = (0..16).collect();
+        let mut seen = Vec::new();
+        // Earlier items sleep longer so they finish last
+        run_ordered(
+            &items,
+            4,
+            |&i| {
+                thread::sleep(Duration::from_millis(16 - i));
+                i * 10
+            },
+            |&i, result| seen.push((i, result)),
+        );
+
+        let expected: Vec<(u64, u64)> = items.iter().map(|&i| (i, i * 10)).collect();
+        assert_eq!(seen, expected);
+    }
+
+    #[test]
+    fn input_source_from_arg() {
+        assert_eq!(InputSource::from_arg("-"), InputSource::Stdin);
+        assert_eq!(
+            InputSource::from_arg("other/alice.txt"),
+            InputSource::File(PathBuf::from("other/alice.txt"))
+        );
+    }
+
+    #[test]
+    fn example_falls_back_past_an_empty_file() {
+        let dir = std::env::temp_dir().join(format!("aoc2025-runner-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let (unnumbered, first) = (dir.join("example_day04.txt"), dir.join("example_day04_1.txt"));
+
+        // Only the unnumbered file is used while there is nothing else
+        assert_eq!(first_example(unnumbered.clone(), first.clone()), unnumbered);
+        fs::write(&unnumbered, "").unwrap();
+        assert_eq!(first_example(unnumbered.clone(), first.clone()), unnumbered);
+
+        fs::write(&first, "..@\n").unwrap();
+        assert_eq!(first_example(unnumbered.clone(), first.clone()), first);
+        fs::write(&unnumbered, "@@.\n").unwrap();
+        assert_eq!(first_example(unnumbered.clone(), first.clone()), unnumbered);
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn answers_key_for_sources() {
+        assert_eq!(InputSource::default_for(5).answers_key(5).as_deref(), Some("input"));
+        assert_eq!(
+            InputSource::from_arg("inputs/input_day05.txt").answers_key(5).as_deref(),
+            Some("input")
+        );
+        assert_eq!(
+            InputSource::from_arg("./inputs/day05/alice.txt").answers_key(5).as_deref(),
+            Some("alice")
+        );
+        assert_eq!(InputSource::Stdin.answers_key(5), None);
+        // Unregistered files have no answers, even if their name looks right
+        assert_eq!(InputSource::from_arg("stress/big.txt").answers_key(5), None);
+        assert_eq!(InputSource::from_arg("shared/input_day05.txt").answers_key(5), None);
+        assert_eq!(InputSource::from_arg("inputs/day04/alice.txt").answers_key(5), None);
+        assert_eq!(InputSource::from_arg("inputs/example_day05_x.txt").answers_key(5), None);
+        // Named inputs can't take over the default input's or examples' tables
+        assert_eq!(InputSource::from_arg("inputs/day05/input.txt").answers_key(5), None);
+        assert_eq!(InputSource::from_arg("inputs/day05/example_2.txt").answers_key(5), None);
+        assert_eq!(
+            InputSource::from_arg("inputs/day05/example_day05.txt").answers_key(5).as_deref(),
+            Some("example_day05")
+        );
+        assert_eq!(
+            InputSource::File(example_path(4, None)).answers_key(4).as_deref(),
+            Some("example")
+        );
+        assert_eq!(
+            InputSource::File(example_path(4, Some(2))).answers_key(4).as_deref(),
+            Some("example_2")
+        );
+    }
+}
@@ -0,0 +1,111 @@
+use std::any::Any;
+use std::fmt;
+
+use crate::error::AocResult;
+
+/// A computed puzzle answer.
+///
+/// Days return whatever numeric type is natural for them; everything is
+/// normalised into an `Answer` so the runner can print, compare and
+/// serialise results without knowing the concrete type.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Int(i64),
+    UInt(u64),
+    Text(String),
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Int(n) => write!(f, "{}", n),
+            Answer::UInt(n) => write!(f, "{}", n),
+            Answer::Text(s) => write!(f, "{}", s),
+        }
+    }
+}
+
+macro_rules! answer_from {
+    ($variant:ident as $target:ty: $($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(value: $t) -> Self {
+                    Answer::$variant(value as $target)
+                }
+            }
+        )*
+    };
+}
+
+answer_from!(UInt as u64: u8, u16, u32, u64, usize);
+answer_from!(Int as i64: i8, i16, i32, i64, isize);
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::Text(value.to_string())
+    }
+}
+
+/// A single day's puzzle.
+///
+/// `parse` turns the raw input text into `Input` once; both parts then work
+/// from the parsed value, so the runner can time (and repeat) each step on
+/// its own. Malformed input is reported as an
+/// [`AocError`](crate::error::AocError) rather than a panic.
+pub trait Solution {
+    /// Day number, 1..=25.
+    const DAY: u8;
+
+    /// Parsed form of the puzzle input shared by both parts.
+    type Input: 'static;
+
+    fn parse(&self, input: &str) -> AocResult<Self::Input>;
+    fn part1(&self, input: &Self::Input) -> AocResult<Answer>;
+    fn part2(&self, input: &Self::Input) -> AocResult<Answer>;
+}
+
+/// Object-safe view of a [`Solution`] used by the registry.
+///
+/// The parsed input is boxed as `dyn Any` and handed back to the same
+/// solution for each part, so the runner never needs the concrete type.
+/// Errors come back with the day filled in, and parse errors with the
+/// offending input line.
+pub trait DynSolution: Sync {
+    fn day(&self) -> u8;
+    fn parse(&self, input: &str) -> AocResult<Box<dyn Any>>;
+    fn part1(&self, input: &dyn Any) -> AocResult<Answer>;
+    fn part2(&self, input: &dyn Any) -> AocResult<Answer>;
+}
+
+impl<S: Solution + Sync> DynSolution for S {
+    fn day(&self) -> u8 {
+        S::DAY
+    }
+
+    fn parse(&self, input: &str) -> AocResult<Box<dyn Any>> {
+        match Solution::parse(self, input) {
+            Ok(parsed) => Ok(Box::new(parsed)),
+            Err(e) => Err(e.for_day(S::DAY).with_source(input)),
+        }
+    }
+
+    fn part1(&self, input: &dyn Any) -> AocResult<Answer> {
+        Solution::part1(self, downcast::<S>(input)).map_err(|e| e.for_day(S::DAY))
+    }
+
+    fn part2(&self, input: &dyn Any) -> AocResult<Answer> {
+        Solution::part2(self, downcast::<S>(input)).map_err(|e| e.for_day(S::DAY))
+    }
+}
+
+fn downcast<S: Solution>(input: &dyn Any) -> &S::Input {
+    input
+        .downcast_ref::<S::Input>()
+        .expect("Parsed input does not belong to this day")
+}
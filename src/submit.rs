@@ -0,0 +1,422 @@
+use std::fmt;
+use std::io;
+use std::path::Path;
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+use crate::answers;
+use crate::client::{Client, ClientError};
+use crate::util;
+
+/// Local log of every answer sent, one JSON record per line.
+pub const SUBMISSIONS_PATH: &str = "submissions.jsonl";
+
+/// What the puzzle site said about a submitted answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Reply {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// Wrong, with no hint which way.
+    Incorrect,
+    /// Rejected unseen because the previous answer was too recent.
+    TooRecent,
+    /// The part was already solved, or isn't unlocked yet.
+    WrongLevel,
+    /// A page we don't know how to read.
+    Unknown,
+}
+
+impl fmt::Display for Reply {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let text = match self {
+            Reply::Correct => "correct",
+            Reply::TooHigh => "too high",
+            Reply::TooLow => "too low",
+            Reply::Incorrect => "incorrect",
+            Reply::TooRecent => "too soon after the previous answer",
+            Reply::WrongLevel => "not the current part; already solved?",
+            Reply::Unknown => "unrecognised response",
+        };
+        write!(f, "{}", text)
+    }
+}
+
+/// A parsed answer page.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Response {
+    pub reply: Reply,
+    /// How long the site wants us to wait before answering again.
+    pub wait: Option<Duration>,
+    /// The page's message as plain text.
+    pub message: String,
+}
+
+/// Text inside the page's `<article>`, without tags or repeated spaces.
+fn article_text(html: &str) -> String {
+    let article = html
+        .split_once("<article")
+        .and_then(|(_, rest)| rest.split_once('>'))
+        .map_or(html, |(_, rest)| rest.split_once("</article>").map_or(rest, |(inner, _)| inner));
+
+    let mut text = String::new();
+    let mut in_tag = false;
+    for c in article.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+fn number_word(word: &str) -> Option<u64> {
+    const WORDS: [&str; 10] = ["one", "two", "three", "four", "five", "six", "seven", "eight", "nine", "ten"];
+    word.parse()
+        .ok()
+        .or_else(|| WORDS.iter().position(|&w| w == word).map(|i| i as u64 + 1))
+}
+
+/// `You have 1m 4s left to wait` or `please wait 5 minutes before trying
+/// again`.
+fn parse_wait(text: &str) -> Option<Duration> {
+    if let Some((_, rest)) = text.split_once("You have ")
+        && let Some((left, _)) = rest.split_once(" left to wait")
+    {
+        let mut seconds = 0;
+        for token in left.split_whitespace() {
+            let (number, unit) = token.split_at(token.len() - 1);
+            let scale = match unit {
+                "h" => 3600,
+                "m" => 60,
+                "s" => 1,
+                _ => return None,
+            };
+            seconds += number.parse::<u64>().ok()? * scale;
+        }
+        return Some(Duration::from_secs(seconds));
+    }
+
+    let (_, rest) = text.split_once("please wait ")?;
+    let mut words = rest.split_whitespace();
+    let count = number_word(words.next()?)?;
+    let scale = match words.next()? {
+        unit if unit.starts_with("minute") => 60,
+        unit if unit.starts_with("second") => 1,
+        _ => return None,
+    };
+    Some(Duration::from_secs(count * scale))
+}
+
+/// Read the page returned after posting an answer.
+pub fn parse_response(html: &str) -> Response {
+    let message = article_text(html);
+    let reply = if message.contains("That's the right answer") {
+        Reply::Correct
+    } else if message.contains("answer too recently") {
+        Reply::TooRecent
+    } else if message.contains("not the right answer") {
+        if message.contains("too high") {
+            Reply::TooHigh
+        } else if message.contains("too low") {
+            Reply::TooLow
+        } else {
+            Reply::Incorrect
+        }
+    } else if message.contains("solving the right level") {
+        Reply::WrongLevel
+    } else {
+        Reply::Unknown
+    };
+
+    Response {
+        reply,
+        wait: parse_wait(&message),
+        message,
+    }
+}
+
+/// One answer sent to the site.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Submission {
+    /// Seconds since the Unix epoch.
+    pub timestamp: u64,
+    pub day: u8,
+    pub part: u8,
+    pub answer: String,
+    pub reply: Reply,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub wait_secs: Option<u64>,
+}
+
+impl Submission {
+    /// When the site will take another answer for this day.
+    fn cooldown_until(&self) -> u64 {
+        self.timestamp + self.wait_secs.unwrap_or(0)
+    }
+}
+
+pub fn load(path: &Path) -> io::Result<Vec<Submission>> {
+    util::load_jsonl(path)
+}
+
+/// Why an answer was not sent.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Refusal {
+    /// The part already has a correct answer.
+    Solved { answer: String },
+    /// The same answer was already sent and rejected.
+    Repeated { reply: Reply },
+    /// A previous reply already rules this answer out.
+    OutOfBounds { reply: Reply, previous: String },
+    /// The site asked us to wait this much longer.
+    Cooldown { remaining: Duration },
+}
+
+impl fmt::Display for Refusal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Refusal::Solved { answer } => write!(f, "Already solved with {}", answer),
+            Refusal::Repeated { reply } => write!(f, "Already submitted this answer ({})", reply),
+            Refusal::OutOfBounds { reply, previous } => {
+                write!(f, "{} was {}, so this answer is too", previous, reply)
+            }
+            Refusal::Cooldown { remaining } => {
+                write!(f, "Still cooling down; try again in {}s", remaining.as_secs())
+            }
+        }
+    }
+}
+
+/// Check the submission log before sending `answer` at time `now`.
+pub fn check_allowed(log: &[Submission], day: u8, part: u8, answer: &str, now: u64) -> Result<(), Refusal> {
+    let same_day = || log.iter().filter(|s| s.day == day);
+    let same_part = || same_day().filter(|s| s.part == part);
+
+    if let Some(solved) = same_part().find(|s| s.reply == Reply::Correct) {
+        return Err(Refusal::Solved {
+            answer: solved.answer.clone(),
+        });
+    }
+    let judged_wrong = |s: &&Submission| matches!(s.reply, Reply::TooHigh | Reply::TooLow | Reply::Incorrect);
+    if let Some(previous) = same_part().filter(judged_wrong).find(|s| s.answer == answer) {
+        return Err(Refusal::Repeated { reply: previous.reply });
+    }
+
+    if let Ok(value) = answer.parse::<i128>() {
+        for previous in same_part() {
+            let Ok(bound) = previous.answer.parse::<i128>() else { continue };
+            let ruled_out = match previous.reply {
+                Reply::TooHigh => value >= bound,
+                Reply::TooLow => value <= bound,
+                _ => false,
+            };
+            if ruled_out {
+                return Err(Refusal::OutOfBounds {
+                    reply: previous.reply,
+                    previous: previous.answer.clone(),
+                });
+            }
+        }
+    }
+
+    if let Some(until) = same_day().map(Submission::cooldown_until).max()
+        && until > now
+    {
+        return Err(Refusal::Cooldown {
+            remaining: Duration::from_secs(until - now),
+        });
+    }
+    Ok(())
+}
+
+/// Why [`submit`] did not get an answer judged.
+#[derive(Debug)]
+pub enum SubmitError {
+    Refused(Refusal),
+    Client(ClientError),
+}
+
+impl fmt::Display for SubmitError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SubmitError::Refused(refusal) => write!(f, "Not submitted: {}", refusal),
+            SubmitError::Client(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl From<ClientError> for SubmitError {
+    fn from(e: ClientError) -> Self {
+        SubmitError::Client(e)
+    }
+}
+
+impl From<io::Error> for SubmitError {
+    fn from(e: io::Error) -> Self {
+        SubmitError::Client(ClientError::Io(e))
+    }
+}
+
+/// Where [`submit`] keeps its records.
+pub struct Stores<'a> {
+    /// The submission log, normally [`SUBMISSIONS_PATH`].
+    pub log: &'a Path,
+    /// The day's answers file, updated when an answer is correct.
+    pub answers: &'a Path,
+}
+
+/// Send `answer` for one part of a day unless the log says not to, then
+/// record the reply. A correct answer is also stored as the expected
+/// answer for the day's puzzle input.
+pub fn submit(
+    client: &Client,
+    stores: &Stores,
+    day: u8,
+    part: u8,
+    answer: &str,
+) -> Result<Response, SubmitError> {
+    let now = util::unix_now();
+    check_allowed(&load(stores.log)?, day, part, answer, now).map_err(SubmitError::Refused)?;
+
+    let level = part.to_string();
+    let html = client.post_form(
+        &client.day_url(day, "/answer"),
+        &[("level", level.as_str()), ("answer", answer)],
+    )?;
+    let response = parse_response(&html);
+
+    util::append_jsonl(
+        stores.log,
+        &Submission {
+            timestamp: now,
+            day,
+            part,
+            answer: answer.to_string(),
+            reply: response.reply,
+            wait_secs: response.wait.map(|w| w.as_secs()),
+        },
+    )?;
+    if response.reply == Reply::Correct {
+        answers::record(stores.answers, answers::DEFAULT_INPUT, part, answer)?;
+    }
+    Ok(response)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::test_server;
+    use std::fs;
+
+    fn page(message: &str) -> String {
+        format!("<html><body><main><article><p>{}</p></article></main></body></html>", message)
+    }
+
+    #[test]
+    fn parses_site_replies() {
+        let correct = parse_response(&page(
+            "That's the right answer! You are <em>one gold star</em> closer.",
+        ));
+        assert_eq!((correct.reply, correct.wait), (Reply::Correct, None));
+
+        let high = parse_response(&page(
+            "That's not the right answer; your answer is too high. If you're stuck, \
+             please wait one minute before trying again. <a href=\"/2025/day/1\">[Return]</a>",
+        ));
+        assert_eq!((high.reply, high.wait), (Reply::TooHigh, Some(Duration::from_secs(60))));
+
+        let low = parse_response(&page(
+            "That's not the right answer; your answer is too low. please wait 5 minutes before trying again.",
+        ));
+        assert_eq!((low.reply, low.wait), (Reply::TooLow, Some(Duration::from_secs(300))));
+
+        let recent = parse_response(&page(
+            "You gave an answer too recently; you have to wait after submitting an answer \
+             before trying again. You have 1m 4s left to wait.",
+        ));
+        assert_eq!((recent.reply, recent.wait), (Reply::TooRecent, Some(Duration::from_secs(64))));
+
+        let level = parse_response(&page(
+            "You don't seem to be solving the right level. Did you already complete it?",
+        ));
+        assert_eq!(level.reply, Reply::WrongLevel);
+        assert_eq!(parse_response("<html>Oops</html>").reply, Reply::Unknown);
+    }
+
+    fn sent(day: u8, part: u8, answer: &str, reply: Reply, timestamp: u64, wait: Option<u64>) -> Submission {
+        Submission {
+            timestamp,
+            day,
+            part,
+            answer: answer.to_string(),
+            reply,
+            wait_secs: wait,
+        }
+    }
+
+    #[test]
+    fn check_allowed_uses_the_log() {
+        let log = vec![
+            sent(1, 1, "1118", Reply::Correct, 0, None),
+            sent(2, 1, "500", Reply::TooHigh, 1000, Some(60)),
+            sent(2, 1, "100", Reply::TooLow, 1100, Some(60)),
+        ];
+
+        assert!(matches!(check_allowed(&log, 1, 1, "1", 5000), Err(Refusal::Solved { .. })));
+        assert!(matches!(check_allowed(&log, 2, 1, "100", 5000), Err(Refusal::Repeated { .. })));
+        assert!(matches!(check_allowed(&log, 2, 1, "600", 5000), Err(Refusal::OutOfBounds { .. })));
+        assert!(matches!(check_allowed(&log, 2, 1, "50", 5000), Err(Refusal::OutOfBounds { .. })));
+        assert_eq!(
+            check_allowed(&log, 2, 1, "300", 1130),
+            Err(Refusal::Cooldown { remaining: Duration::from_secs(30) })
+        );
+        assert_eq!(check_allowed(&log, 2, 1, "300", 1160), Ok(()));
+        assert_eq!(check_allowed(&log, 1, 2, "1", 0), Ok(()));
+    }
+
+    #[test]
+    fn submit_end_to_end_against_fake_server() {
+        let dir = std::env::temp_dir().join(format!("aoc2025-submit-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        let stores = Stores {
+            log: &dir.join("submissions.jsonl"),
+            answers: &dir.join("day07.toml"),
+        };
+        let (base_url, requests) = test_server::serve(vec![
+            (200, page("That's not the right answer; your answer is too low. please wait one minute before trying again.")),
+            (200, page("That's the right answer!")),
+        ]);
+        let client = Client::new(&base_url, "abc123", None);
+
+        let response = submit(&client, &stores, 7, 2, "41").unwrap();
+        assert_eq!(response.reply, Reply::TooLow);
+        let request = requests.recv().unwrap();
+        assert_eq!(request.line, "POST /2025/day/7/answer HTTP/1.1");
+        assert_eq!(request.body, "level=2&answer=41");
+        assert!(!stores.answers.exists());
+
+        // Still cooling down: refused locally without contacting the server
+        let refused = submit(&client, &stores, 7, 2, "42").unwrap_err();
+        assert!(matches!(refused, SubmitError::Refused(Refusal::Cooldown { .. })), "{}", refused);
+
+        // Pretend the minute has passed
+        let mut log = load(stores.log).unwrap();
+        log[0].timestamp -= 60;
+        fs::remove_file(stores.log).unwrap();
+        util::append_jsonl(stores.log, &log[0]).unwrap();
+
+        assert_eq!(submit(&client, &stores, 7, 2, "42").unwrap().reply, Reply::Correct);
+        assert_eq!(requests.recv().unwrap().body, "level=2&answer=42");
+        let expected = answers::load_file(stores.answers).unwrap();
+        assert_eq!(expected["input"].part(2), Some("42"));
+        assert_eq!(load(stores.log).unwrap().len(), 2);
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
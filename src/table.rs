@@ -0,0 +1,226 @@
+use std::time::Duration;
+
+use clap::ValueEnum;
+use colored::{ColoredString, Colorize};
+
+use crate::report::{PartReport, Status};
+
+/// When to use colored output.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum ColorChoice {
+    /// Color when stdout is a terminal, unless NO_COLOR is set
+    Auto,
+    Always,
+    Never,
+}
+
+impl ColorChoice {
+    /// Configure `colored` globally. `Auto` leaves the decision to its
+    /// own `NO_COLOR` / `CLICOLOR` / TTY detection.
+    pub fn apply(self) {
+        match self {
+            ColorChoice::Auto => colored::control::unset_override(),
+            ColorChoice::Always => colored::control::set_override(true),
+            ColorChoice::Never => colored::control::set_override(false),
+        }
+    }
+}
+
+fn status_text(status: Status) -> &'static str {
+    match status {
+        Status::Pass => "PASS",
+        Status::Fail => "FAIL",
+        Status::Unknown => "UNKNOWN",
+        Status::Error => "ERROR",
+        Status::Panic => "PANIC",
+        Status::Timeout => "TIMEOUT",
+    }
+}
+
+/// Color `text` for a status: green for pass, yellow for unknown, red for
+/// anything that failed.
+pub fn color_status(text: &str, status: Status) -> ColoredString {
+    match status {
+        Status::Pass => text.green(),
+        Status::Unknown => text.yellow(),
+        Status::Fail | Status::Error | Status::Panic | Status::Timeout => text.red(),
+    }
+}
+
+/// Time shown for a part: the median under `--stats`, otherwise the single
+/// measured run.
+fn part_time(report: &PartReport) -> Option<Duration> {
+    report
+        .stats
+        .as_ref()
+        .map(|s| s.solve.median_ns)
+        .or_else(|| report.timings.as_ref().map(|t| t.solve_ns))
+        .map(Duration::from_nanos)
+}
+
+/// Render reports as an aligned table of day, part, answer, time and
+/// status. Times at or above `slow` are highlighted. An input column is
+/// added when some day was run against more than one input.
+pub fn render(reports: &[PartReport], slow: Duration) -> String {
+    render_table(reports, None, slow)
+}
+
+/// Like [`render`], with each part's answer from `previous` shown before
+/// the current one.
+pub fn render_compared(reports: &[PartReport], previous: &[PartReport], slow: Duration) -> String {
+    render_table(reports, Some(previous), slow)
+}
+
+fn render_table(reports: &[PartReport], previous: Option<&[PartReport]>, slow: Duration) -> String {
+    let show_input = reports
+        .iter()
+        .any(|r| reports.iter().any(|o| o.day == r.day && o.input != r.input));
+    let answer = |r: Option<&PartReport>| {
+        r.and_then(|r| r.answer.clone())
+            .unwrap_or_else(|| "-".to_string())
+    };
+
+    let mut headers = vec!["Day", "Part"];
+    if show_input {
+        headers.push("Input");
+    }
+    if previous.is_some() {
+        headers.push("Previous");
+    }
+    headers.extend(["Answer", "Time", "Status"]);
+
+    let rows: Vec<Vec<String>> = reports
+        .iter()
+        .map(|r| {
+            let mut cells = vec![r.day.to_string(), r.part.to_string()];
+            if show_input {
+                cells.push(r.input.clone());
+            }
+            if let Some(previous) = previous {
+                let before = previous
+                    .iter()
+                    .find(|p| (p.day, p.part, &p.input) == (r.day, r.part, &r.input));
+                cells.push(answer(before));
+            }
+            cells.push(answer(Some(r)));
+            cells.push(part_time(r).map_or_else(|| "-".to_string(), |t| format!("{:.2?}", t)));
+            cells.push(status_text(r.status).to_string());
+            cells
+        })
+        .collect();
+
+    let mut widths: Vec<usize> = headers.iter().map(|h| h.len()).collect();
+    for cells in &rows {
+        for (width, cell) in widths.iter_mut().zip(cells) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+    let time_col = headers.len() - 2;
+
+    let mut out = String::new();
+    let header: Vec<String> = headers
+        .iter()
+        .zip(&widths)
+        .map(|(h, w)| format!("{:<w$}", h, w = w))
+        .collect();
+    out.push_str(&header.join("  ").trim_end().bold().to_string());
+    out.push('\n');
+    let rule: Vec<String> = widths.iter().map(|&w| "-".repeat(w)).collect();
+    out.push_str(&rule.join("  "));
+    out.push('\n');
+
+    // Pad before coloring so escape codes don't throw off the alignment
+    for (report, cells) in reports.iter().zip(&rows) {
+        let line: Vec<String> = cells
+            .iter()
+            .zip(&widths)
+            .enumerate()
+            .map(|(col, (cell, &w))| match col {
+                0 | 1 => format!("{:>w$}", cell, w = w),
+                _ if col == time_col => {
+                    let time = format!("{:>w$}", cell, w = w);
+                    match part_time(report) {
+                        Some(t) if t >= slow => time.red().bold().to_string(),
+                        _ => time,
+                    }
+                }
+                _ if col == time_col + 1 => color_status(cell, report.status).to_string(),
+                _ => format!("{:<w$}", cell, w = w),
+            })
+            .collect();
+        out.push_str(&line.join("  "));
+        out.push('\n');
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::report::PartTimings;
+
+    fn part(day: u8, part: u8, answer: &str, solve_ms: u64, status: Status) -> PartReport {
+        PartReport {
+            day,
+            part,
+            input: format!("inputs/input_day{:02}.txt", day),
+            answer: Some(answer.to_string()),
+            expected: None,
+            status,
+            timings: Some(PartTimings {
+                read_ns: 0,
+                parse_ns: 0,
+                solve_ns: solve_ms * 1_000_000,
+            }),
+            stats: None,
+            allocs: None,
+            error: None,
+        }
+    }
+
+    #[test]
+    fn render_aligns_columns() {
+        colored::control::set_override(false);
+        let table = render(
+            &[
+                part(1, 1, "1118", 1, Status::Pass),
+                part(12, 2, "79183223243", 300, Status::Unknown),
+            ],
+            Duration::from_secs(1),
+        );
+
+        let lines: Vec<&str> = table.lines().collect();
+        assert_eq!(lines[0], "Day  Part  Answer       Time      Status");
+        assert_eq!(lines[1], "---  ----  -----------  --------  -------");
+        assert_eq!(lines[2], "  1     1  1118           1.00ms  PASS");
+        assert_eq!(lines[3], " 12     2  79183223243  300.00ms  UNKNOWN");
+    }
+
+    #[test]
+    fn render_compared_shows_previous_answers() {
+        colored::control::set_override(false);
+        let table = render_compared(
+            &[part(4, 1, "14", 1, Status::Fail), part(4, 2, "43", 2, Status::Pass)],
+            &[part(4, 1, "13", 1, Status::Pass)],
+            Duration::from_secs(1),
+        );
+
+        let lines: Vec<&str> = table.lines().collect();
+        assert_eq!(lines[0], "Day  Part  Previous  Answer  Time    Status");
+        assert_eq!(lines[2], "  4     1  13        14      1.00ms  FAIL");
+        assert_eq!(lines[3], "  4     2  -         43      2.00ms  PASS");
+    }
+
+    #[test]
+    fn render_shows_input_when_a_day_has_several() {
+        colored::control::set_override(false);
+        let mut bob = part(2, 1, "7", 1, Status::Fail);
+        bob.input = "inputs/day02/bob.txt".to_string();
+        let table = render(&[part(2, 1, "12", 1, Status::Pass), bob], Duration::from_secs(1));
+
+        let lines: Vec<&str> = table.lines().collect();
+        assert_eq!(lines[0], "Day  Part  Input                   Answer  Time    Status");
+        assert_eq!(lines[2], "  2     1  inputs/input_day02.txt  12      1.00ms  PASS");
+        assert_eq!(lines[3], "  2     1  inputs/day02/bob.txt    7       1.00ms  FAIL");
+    }
+}
@@ -0,0 +1,157 @@
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::path::Path;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+/// Parse durations like `250ms`, `2s`, `1.5m` or a bare number of seconds.
+pub fn parse_duration(s: &str) -> Result<Duration, String> {
+    let s = s.trim();
+    let split = s.find(|c: char| !(c.is_ascii_digit() || c == '.')).unwrap_or(s.len());
+    let (number, unit) = s.split_at(split);
+    let value: f64 = number
+        .parse()
+        .map_err(|_| format!("Invalid duration '{}'", s))?;
+    let seconds = match unit.trim() {
+        "" | "s" => value,
+        "ms" => value / 1_000.0,
+        "us" | "µs" => value / 1_000_000.0,
+        "m" => value * 60.0,
+        other => return Err(format!("Unknown duration unit '{}' (use ms, s or m)", other)),
+    };
+    // Too large to fit, rather than panicking
+    Duration::try_from_secs_f64(seconds).map_err(|e| format!("Invalid duration '{}': {}", s, e))
+}
+
+/// Days picked on the command line, sorted and without repeats.
+///
+/// A newtype so clap treats a whole set as one value rather than
+/// collecting one day per occurrence, as it would for a `Vec`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DaySet(pub Vec<u8>);
+
+/// Parse a set of days like `1-3,5,9`: comma-separated days and inclusive
+/// ranges, each within 1..=25.
+pub fn parse_days(s: &str) -> Result<DaySet, String> {
+    let day = |d: &str| -> Result<u8, String> {
+        match d.trim().parse() {
+            Ok(day @ 1..=25) => Ok(day),
+            _ => Err(format!("Invalid day '{}' (expected 1-25)", d.trim())),
+        }
+    };
+
+    let mut days = Vec::new();
+    for item in s.split(',') {
+        match item.split_once('-') {
+            Some((from, to)) => {
+                let (from, to) = (day(from)?, day(to)?);
+                if from > to {
+                    return Err(format!("Empty range '{}'", item.trim()));
+                }
+                days.extend(from..=to);
+            }
+            None => days.push(day(item)?),
+        }
+    }
+    days.sort_unstable();
+    days.dedup();
+    Ok(DaySet(days))
+}
+
+/// The inverse of [`parse_days`]: sorted days written with ranges, e.g.
+/// `1-3,5,9`.
+pub fn format_days(days: &[u8]) -> String {
+    let mut ranges: Vec<(u8, u8)> = Vec::new();
+    for &day in days {
+        match ranges.last_mut() {
+            Some((_, end)) if *end + 1 == day => *end = day,
+            _ => ranges.push((day, day)),
+        }
+    }
+    ranges
+        .iter()
+        .map(|&(start, end)| {
+            if start == end {
+                start.to_string()
+            } else {
+                format!("{}-{}", start, end)
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+/// Seconds since the Unix epoch.
+pub fn unix_now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+/// Load every record of a JSON Lines file. A missing file has none.
+pub fn load_jsonl<T: DeserializeOwned>(path: &Path) -> io::Result<Vec<T>> {
+    let content = match fs::read_to_string(path) {
+        Ok(content) => content,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => return Err(e),
+    };
+
+    content
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .enumerate()
+        .map(|(i, line)| {
+            serde_json::from_str(line).map_err(|e| {
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("{}:{}: {}", path.display(), i + 1, e),
+                )
+            })
+        })
+        .collect()
+}
+
+/// Append one record to a JSON Lines file, creating it if needed.
+pub fn append_jsonl<T: Serialize>(path: &Path, record: &T) -> io::Result<()> {
+    let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+    let line = serde_json::to_string(record).map_err(io::Error::other)?;
+    writeln!(file, "{}", line)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_duration_units() {
+        assert_eq!(parse_duration("250ms"), Ok(Duration::from_millis(250)));
+        assert_eq!(parse_duration("2s"), Ok(Duration::from_secs(2)));
+        assert_eq!(parse_duration("3"), Ok(Duration::from_secs(3)));
+        assert_eq!(parse_duration("1.5m"), Ok(Duration::from_secs(90)));
+        assert_eq!(parse_duration("10us"), Ok(Duration::from_micros(10)));
+        assert!(parse_duration("5h").is_err());
+        assert!(parse_duration("fast").is_err());
+        assert!(parse_duration(&format!("{}m", "9".repeat(40))).is_err());
+    }
+
+    #[test]
+    fn parse_days_lists_and_ranges() {
+        assert_eq!(parse_days("1-3,5"), Ok(DaySet(vec![1, 2, 3, 5])));
+        assert_eq!(parse_days("9, 2-3 ,3"), Ok(DaySet(vec![2, 3, 9])));
+        assert_eq!(parse_days("25"), Ok(DaySet(vec![25])));
+        assert!(parse_days("0").is_err());
+        assert!(parse_days("1-26").is_err());
+        assert!(parse_days("5-3").is_err());
+        assert!(parse_days("1,,2").is_err());
+    }
+
+    #[test]
+    fn format_days_round_trips() {
+        assert_eq!(format_days(&[1, 2, 3, 5, 7, 8]), "1-3,5,7-8");
+        assert_eq!(parse_days(&format_days(&[4, 6, 7, 8, 25])), Ok(DaySet(vec![4, 6, 7, 8, 25])));
+        assert_eq!(format_days(&[]), "");
+    }
+}
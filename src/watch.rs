@@ -0,0 +1,145 @@
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::thread;
+use std::time::{Duration, SystemTime};
+
+use crate::report::PartReport;
+use crate::runner::{self, example_path, input_path, InputSource};
+
+/// How often watched files are checked for changes.
+pub const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// The day's source, puzzle input, example inputs and named inputs, plus
+/// `input` if the day is being run against some other file.
+pub fn watched_paths(day: u8, input: Option<&InputSource>) -> Vec<PathBuf> {
+    let mut paths = vec![
+        PathBuf::from(format!("src/day{:02}.rs", day)),
+        input_path(day),
+        example_path(day, None),
+    ];
+
+    // Numbered examples and named inputs may appear while watching
+    let prefix = format!("example_day{:02}_", day);
+    for dir in [PathBuf::from("inputs"), runner::inputs_dir(day)] {
+        let Ok(entries) = fs::read_dir(&dir) else { continue };
+        let mut found: Vec<PathBuf> = entries
+            .filter_map(|entry| entry.ok().map(|e| e.path()))
+            .filter(|path| path.extension().is_some_and(|ext| ext == "txt"))
+            .filter(|path| {
+                dir != Path::new("inputs")
+                    || path
+                        .file_name()
+                        .is_some_and(|name| name.to_string_lossy().starts_with(&prefix))
+            })
+            .collect();
+        found.sort();
+        paths.extend(found);
+    }
+    if let Some(InputSource::File(path)) = input
+        && !paths.contains(path)
+    {
+        paths.push(path.clone());
+    }
+    paths
+}
+
+/// Modification times of every watched path; missing files have none.
+pub type Snapshot = Vec<(PathBuf, Option<SystemTime>)>;
+
+pub fn snapshot(day: u8, input: Option<&InputSource>) -> Snapshot {
+    watched_paths(day, input)
+        .into_iter()
+        .map(|path| {
+            let modified = fs::metadata(&path).and_then(|m| m.modified()).ok();
+            (path, modified)
+        })
+        .collect()
+}
+
+/// Block until a watched file is created, changed or removed. Returns the
+/// paths that changed.
+pub fn wait_for_change(day: u8, input: Option<&InputSource>, before: &Snapshot) -> Vec<PathBuf> {
+    loop {
+        thread::sleep(POLL_INTERVAL);
+        let now = snapshot(day, input);
+        if now != *before {
+            let mut changed: Vec<PathBuf> = now
+                .iter()
+                .filter(|entry| !before.contains(entry))
+                .map(|(path, _)| path.clone())
+                .collect();
+            changed.extend(
+                before
+                    .iter()
+                    .filter(|(path, _)| !now.iter().any(|(p, _)| p == path))
+                    .map(|(path, _)| path.clone()),
+            );
+            return changed;
+        }
+    }
+}
+
+/// Output of one rebuild-and-run.
+#[derive(Debug, Default)]
+pub struct Run {
+    /// Results, or `None` if the build or run produced none.
+    pub reports: Option<Vec<PartReport>>,
+    /// Build errors and warnings, the run's summary and anything else
+    /// written to stderr.
+    pub messages: String,
+}
+
+/// Rebuild and run the runner once with `args`, in JSON mode.
+pub fn run_once(args: &[String]) -> Run {
+    let cargo = env::var("CARGO").unwrap_or_else(|_| "cargo".to_string());
+    let mut command = Command::new(cargo);
+    command.args(["run", "--quiet", "--bin", "aoc2025"]);
+    // Rebuild with the same profile this binary was built with
+    if !cfg!(debug_assertions) {
+        command.arg("--release");
+    }
+    let output = command
+        .arg("--")
+        .args(args)
+        .args(["--format", "json"])
+        .output();
+
+    match output {
+        Ok(output) => Run {
+            reports: serde_json::from_slice(&output.stdout).ok(),
+            messages: String::from_utf8_lossy(&output.stderr).into_owned(),
+        },
+        Err(e) => Run {
+            reports: None,
+            messages: format!("Failed to run cargo: {}", e),
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn watches_source_inputs_and_examples() {
+        let paths = watched_paths(4, None);
+        assert_eq!(paths[0], PathBuf::from("src/day04.rs"));
+        assert!(paths.contains(&PathBuf::from("inputs/input_day04.txt")));
+        assert!(paths.contains(&PathBuf::from("inputs/example_day04.txt")));
+        assert!(!paths.iter().any(|p| p.to_string_lossy().contains("day01")));
+    }
+
+    #[test]
+    fn watches_an_explicit_input_file() {
+        let other = InputSource::from_arg("stress/big.txt");
+        let paths = watched_paths(4, Some(&other));
+        assert_eq!(paths.last(), Some(&PathBuf::from("stress/big.txt")));
+
+        // Stdin and already-watched files add nothing
+        let count = watched_paths(4, None).len();
+        assert_eq!(watched_paths(4, Some(&InputSource::Stdin)).len(), count);
+        assert_eq!(watched_paths(4, Some(&InputSource::default_for(4))).len(), count);
+    }
+}
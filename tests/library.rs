@@ -0,0 +1,65 @@
+use std::time::Duration;
+
+use aoc2025::runner::{self, Input, InputSource, Parts};
+use aoc2025::failure::Failure;
+use aoc2025::solution::Answer;
+use aoc2025::{day04, day05, find_solution, SOLUTIONS};
+
+const DAY04_EXAMPLE: &str = "\
+..@@.@@@@.
+@@@.@.@.@@
+@@@@@.@.@@
+@.@@@@..@.
+@@.@@@@.@@
+.@@@@@@@.@
+.@.@.@.@@@
+@.@@@.@@@@
+.@@@@@@@@.
+@.@.@@@.@.
+";
+
+#[test]
+fn registry_contains_each_day_once_in_order() {
+    let days: Vec<u8> = SOLUTIONS.iter().map(|s| s.day()).collect();
+    assert!(days.windows(2).all(|w| w[0] < w[1]), "{:?}", days);
+    assert!(days.starts_with(&[1, 2, 3, 4, 5]));
+    assert!(find_solution(26).is_none());
+}
+
+#[test]
+fn day_helpers_are_public() {
+    let (grid, rows, cols) = day04::parse(DAY04_EXAMPLE).unwrap();
+    assert_eq!((rows, cols, grid.len()), (10, 10, 100));
+
+    assert_eq!(day05::consolidate_ranges(vec![(10, 14), (3, 5), (12, 18)]), vec![(3, 5), (10, 18)]);
+}
+
+#[test]
+fn run_day_through_the_registry() {
+    let input = Input {
+        source: InputSource::Stdin,
+        text: DAY04_EXAMPLE.to_string(),
+        read_time: Duration::ZERO,
+    };
+    let result = runner::run_day(find_solution(4).unwrap(), &input, None, Parts::Both);
+
+    assert_eq!(result.part1, Some(Ok(Answer::UInt(13))));
+    assert_eq!(result.part2, Some(Ok(Answer::UInt(43))));
+}
+
+#[test]
+fn bad_input_is_shown_with_a_caret() {
+    colored::control::set_override(false);
+    let input = Input {
+        source: InputSource::Stdin,
+        text: "123\n1x3\n".to_string(),
+        read_time: Duration::ZERO,
+    };
+    let result = runner::run_day(find_solution(3).unwrap(), &input, None, Parts::Both);
+
+    let Some(Err(Failure::Error { error, .. })) = result.part1 else {
+        panic!("Expected day 3 to reject the input");
+    };
+    let diagnostic = error.diagnostic(&input.source.to_string()).unwrap();
+    assert!(diagnostic.ends_with("2 | 1x3\n  |  ^"), "{}", diagnostic);
+}